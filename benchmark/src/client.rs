@@ -13,7 +13,8 @@ use futures_util::{
     FutureExt as _, SinkExt as _, StreamExt as _, TryFutureExt as _, TryStreamExt as _,
 };
 use grpcio::{
-    CallOption, Channel, ChannelBuilder, Client as GrpcClient, EnvBuilder, Environment, WriteFlags,
+    ArgValue, CallOption, Channel, ChannelBuilder, Client as GrpcClient, EnvBuilder, Environment,
+    WriteFlags,
 };
 use grpcio_proto::testing::control::{ClientConfig, ClientType, RpcType};
 use grpcio_proto::testing::messages::SimpleRequest;
@@ -362,12 +363,16 @@ impl Client {
             .map(|(_, addr)| {
                 let mut builder = ChannelBuilder::new(ch_env.clone());
                 for arg in cfg.get_channel_args() {
-                    let key = CString::new(arg.get_name()).unwrap();
                     if arg.has_str_value() {
-                        builder =
-                            builder.raw_cfg_string(key, CString::new(arg.get_str_value()).unwrap());
+                        builder = builder.raw_arg(
+                            arg.get_name(),
+                            ArgValue::String(CString::new(arg.get_str_value()).unwrap()),
+                        );
                     } else if arg.has_int_value() {
-                        builder = builder.raw_cfg_int(key, arg.get_int_value() as i32);
+                        builder = builder.raw_arg(
+                            arg.get_name(),
+                            ArgValue::Integer(arg.get_int_value() as i32),
+                        );
                     }
                 }
                 // Check https://github.com/grpc/grpc/issues/31465.
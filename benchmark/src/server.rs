@@ -10,7 +10,8 @@ use grpc_proto::testing::services_grpc::create_benchmark_service;
 use grpc_proto::testing::stats::ServerStats;
 use grpc_proto::util as proto_util;
 use grpcio::{
-    ChannelBuilder, EnvBuilder, Result, Server as GrpcServer, ServerBuilder, ShutdownFuture,
+    ArgValue, ChannelBuilder, EnvBuilder, Result, Server as GrpcServer, ServerBuilder,
+    ShutdownFuture,
 };
 
 use crate::bench::{self, Benchmark, Generic};
@@ -52,12 +53,16 @@ impl Server {
         if !cfg.get_channel_args().is_empty() {
             let mut ch_builder = ChannelBuilder::new(env);
             for arg in cfg.get_channel_args() {
-                let key = CString::new(arg.get_name()).unwrap();
                 if arg.has_str_value() {
-                    ch_builder =
-                        ch_builder.raw_cfg_string(key, CString::new(arg.get_str_value()).unwrap());
+                    ch_builder = ch_builder.raw_arg(
+                        arg.get_name(),
+                        ArgValue::String(CString::new(arg.get_str_value()).unwrap()),
+                    );
                 } else if arg.has_int_value() {
-                    ch_builder = ch_builder.raw_cfg_int(key, arg.get_int_value() as i32);
+                    ch_builder = ch_builder.raw_arg(
+                        arg.get_name(),
+                        ArgValue::Integer(arg.get_int_value() as i32),
+                    );
                 }
             }
             builder = builder.channel_args(ch_builder.build_args());
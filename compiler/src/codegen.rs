@@ -23,6 +23,7 @@
 
 use std::collections::HashMap;
 use std::io::Write;
+use std::process;
 
 use protobuf::compiler_plugin;
 use protobuf::descriptor::*;
@@ -121,18 +122,18 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("impl {}", name.as_ref()), cb);
     }
 
-    pub fn pub_struct<S: AsRef<str>, F>(&mut self, name: S, cb: F)
+    pub fn vis_struct<S: AsRef<str>, F>(&mut self, vis: &str, name: S, cb: F)
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub struct {}", name.as_ref()), cb);
+        self.expr_block(&format!("{} struct {}", vis, name.as_ref()), cb);
     }
 
-    pub fn pub_trait<F>(&mut self, name: &str, cb: F)
+    pub fn vis_trait<F>(&mut self, vis: &str, name: &str, cb: F)
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub trait {}", name), cb);
+        self.expr_block(&format!("{} trait {}", vis, name), cb);
     }
 
     pub fn field_entry(&mut self, name: &str, value: &str) {
@@ -151,6 +152,51 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
+    /// Write proto comments as doc comments.
+    ///
+    /// Indented blocks are fenced as text, otherwise rustdoc treats them as doctests.
+    pub fn doc_comment(&mut self, doc: Option<&str>) {
+        let doc = match doc {
+            Some(d) => d,
+            None => return,
+        };
+        let lines: Vec<_> = doc.trim_end().lines().map(str::trim_end).collect();
+        // protoc keeps the space after `//`, which is stripped together with the
+        // common indentation.
+        let indent = lines
+            .iter()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.len() - l.trim_start_matches([' ', '\t']).len())
+            .min()
+            .unwrap_or(0);
+        let mut in_code = false;
+        let mut last_empty = true;
+        for line in lines {
+            let line = if line.is_empty() {
+                line
+            } else {
+                &line[indent..]
+            };
+            let is_code = line.starts_with("    ") || line.starts_with('\t');
+            if !in_code && is_code && last_empty {
+                self.write_line("/// ```text");
+                in_code = true;
+            } else if in_code && !is_code && !line.is_empty() {
+                self.write_line("/// ```");
+                in_code = false;
+            }
+            if line.is_empty() {
+                self.write_line("///");
+            } else {
+                self.write_line(&format!("/// {}", line));
+            }
+            last_empty = line.is_empty();
+        }
+        if in_code {
+            self.write_line("/// ```");
+        }
+    }
+
     pub fn fn_block<F>(&mut self, public: bool, sig: &str, cb: F)
     where
         F: Fn(&mut CodeWriter),
@@ -162,21 +208,40 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
-    pub fn pub_fn<F>(&mut self, sig: &str, cb: F)
+    pub fn vis_fn<F>(&mut self, vis: &str, sig: &str, cb: F)
     where
         F: Fn(&mut CodeWriter),
     {
-        self.fn_block(true, sig, cb);
+        self.expr_block(&format!("{} fn {}", vis, sig), cb);
     }
 }
 
+use super::options::GenOptions;
 use super::util::{self, fq_grpc, to_snake_case, MethodType};
 
+/// Find the leading comments of the element at `path` in the file.
+///
+/// See `SourceCodeInfo` in descriptor.proto for how path is constructed.
+fn leading_comments<'a>(file: &'a FileDescriptorProto, path: &[i32]) -> Option<&'a str> {
+    file.get_source_code_info()
+        .get_location()
+        .iter()
+        .find(|l| l.get_path() == path)
+        .map(|l| l.get_leading_comments())
+        .filter(|c| !c.trim().is_empty())
+}
+
+// Field numbers in descriptor.proto.
+const FILE_SERVICE_FIELD: i32 = 6;
+const SERVICE_METHOD_FIELD: i32 = 2;
+
 struct MethodGen<'a> {
     proto: &'a MethodDescriptorProto,
     service_name: String,
     service_path: String,
     root_scope: &'a RootScope<'a>,
+    opts: &'a GenOptions,
+    doc: Option<&'a str>,
 }
 
 impl<'a> MethodGen<'a> {
@@ -185,12 +250,16 @@ impl<'a> MethodGen<'a> {
         service_name: String,
         service_path: String,
         root_scope: &'a RootScope<'a>,
+        opts: &'a GenOptions,
+        doc: Option<&'a str>,
     ) -> MethodGen<'a> {
         MethodGen {
             proto,
             service_name,
             service_path,
             root_scope,
+            opts,
+            doc,
         }
     }
 
@@ -396,7 +465,8 @@ impl<'a> MethodGen<'a> {
         match self.method_type().0 {
             // Unary
             MethodType::Unary => {
-                w.pub_fn(&self.unary_opt(&method_name), |w| {
+                w.doc_comment(self.doc);
+                w.vis_fn(&self.opts.visibility, &self.unary_opt(&method_name), |w| {
                     w.write_line(&format!(
                         "self.client.unary_call(&{}, req, opt)",
                         self.const_method_name()
@@ -404,7 +474,8 @@ impl<'a> MethodGen<'a> {
                 });
                 w.write_line("");
 
-                w.pub_fn(&self.unary(&method_name), |w| {
+                w.doc_comment(self.doc);
+                w.vis_fn(&self.opts.visibility, &self.unary(&method_name), |w| {
                     w.write_line(&format!(
                         "self.{}_opt(req, {})",
                         method_name,
@@ -413,83 +484,124 @@ impl<'a> MethodGen<'a> {
                 });
                 w.write_line("");
 
-                w.pub_fn(&self.unary_async_opt(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.client.unary_call_async(&{}, req, opt)",
-                        self.const_method_name()
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.unary_async_opt(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.client.unary_call_async(&{}, req, opt)",
+                            self.const_method_name()
+                        ));
+                    },
+                );
                 w.write_line("");
 
-                w.pub_fn(&self.unary_async(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.{}_async_opt(req, {})",
-                        method_name,
-                        fq_grpc("CallOption::default()")
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.unary_async(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}_async_opt(req, {})",
+                            method_name,
+                            fq_grpc("CallOption::default()")
+                        ));
+                    },
+                );
             }
 
             // Client streaming
             MethodType::ClientStreaming => {
-                w.pub_fn(&self.client_streaming_opt(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.client.client_streaming(&{}, opt)",
-                        self.const_method_name()
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.client_streaming_opt(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.client.client_streaming(&{}, opt)",
+                            self.const_method_name()
+                        ));
+                    },
+                );
                 w.write_line("");
 
-                w.pub_fn(&self.client_streaming(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.{}_opt({})",
-                        method_name,
-                        fq_grpc("CallOption::default()")
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.client_streaming(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}_opt({})",
+                            method_name,
+                            fq_grpc("CallOption::default()")
+                        ));
+                    },
+                );
             }
 
             // Server streaming
             MethodType::ServerStreaming => {
-                w.pub_fn(&self.server_streaming_opt(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.client.server_streaming(&{}, req, opt)",
-                        self.const_method_name()
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.server_streaming_opt(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.client.server_streaming(&{}, req, opt)",
+                            self.const_method_name()
+                        ));
+                    },
+                );
                 w.write_line("");
 
-                w.pub_fn(&self.server_streaming(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.{}_opt(req, {})",
-                        method_name,
-                        fq_grpc("CallOption::default()")
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.server_streaming(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}_opt(req, {})",
+                            method_name,
+                            fq_grpc("CallOption::default()")
+                        ));
+                    },
+                );
             }
 
             // Duplex streaming
             MethodType::Duplex => {
-                w.pub_fn(&self.duplex_streaming_opt(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.client.duplex_streaming(&{}, opt)",
-                        self.const_method_name()
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.duplex_streaming_opt(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.client.duplex_streaming(&{}, opt)",
+                            self.const_method_name()
+                        ));
+                    },
+                );
                 w.write_line("");
 
-                w.pub_fn(&self.duplex_streaming(&method_name), |w| {
-                    w.write_line(&format!(
-                        "self.{}_opt({})",
-                        method_name,
-                        fq_grpc("CallOption::default()")
-                    ));
-                });
+                w.doc_comment(self.doc);
+                w.vis_fn(
+                    &self.opts.visibility,
+                    &self.duplex_streaming(&method_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}_opt({})",
+                            method_name,
+                            fq_grpc("CallOption::default()")
+                        ));
+                    },
+                );
             }
         };
     }
 
-    fn write_service(&self, w: &mut CodeWriter) {
+    // Returns the name and type of request, and type of sink.
+    fn service_params(&self) -> (&'static str, String, String) {
         let req_stream_type = format!("{}<{}>", fq_grpc("RequestStream"), self.input());
         let (req, req_type, resp_type) = match self.method_type().0 {
             MethodType::Unary => ("req", self.input(), "UnarySink"),
@@ -497,20 +609,61 @@ impl<'a> MethodGen<'a> {
             MethodType::ServerStreaming => ("req", self.input(), "ServerStreamingSink"),
             MethodType::Duplex => ("stream", req_stream_type, "DuplexSink"),
         };
+        let sink_type = format!("{}<{}>", fq_grpc(resp_type), self.output());
+        (req, req_type, sink_type)
+    }
+
+    fn write_service(&self, w: &mut CodeWriter) {
+        let (req, req_type, sink_type) = self.service_params();
         let sig = format!(
-            "{}(&mut self, ctx: {}, _{}: {}, sink: {}<{}>)",
+            "{}(&mut self, ctx: {}, _{}: {}, sink: {})",
             self.name(),
             fq_grpc("RpcContext"),
             req,
             req_type,
-            fq_grpc(resp_type),
-            self.output()
+            sink_type
         );
+        w.doc_comment(self.doc);
         w.fn_block(false, &sig, |w| {
             w.write_line("grpcio::unimplemented_call!(ctx, sink)");
         });
     }
 
+    fn mock_handler_type(&self) -> String {
+        let (_, req_type, sink_type) = self.service_params();
+        format!(
+            "::std::option::Option<::std::sync::Arc<dyn Fn({}, {}, {}) + Send + Sync>>",
+            fq_grpc("RpcContext"),
+            req_type,
+            sink_type
+        )
+    }
+
+    fn write_mock_field(&self, w: &mut CodeWriter) {
+        w.field_decl(
+            &format!("{} {}", self.opts.visibility, self.name()),
+            &self.mock_handler_type(),
+        );
+    }
+
+    fn write_mock(&self, w: &mut CodeWriter) {
+        let (req, req_type, sink_type) = self.service_params();
+        let sig = format!(
+            "{}(&mut self, ctx: {}, {}: {}, sink: {})",
+            self.name(),
+            fq_grpc("RpcContext"),
+            req,
+            req_type,
+            sink_type
+        );
+        w.fn_block(false, &sig, |w| {
+            w.expr_block(&format!("match &self.{}", self.name()), |w| {
+                w.write_line(&format!("Some(f) => f(ctx, {}, sink),", req));
+                w.write_line("None => grpcio::unimplemented_call!(ctx, sink),");
+            });
+        });
+    }
+
     fn write_bind(&self, w: &mut CodeWriter) {
         let add = match self.method_type().0 {
             MethodType::Unary => "add_unary_handler",
@@ -535,14 +688,26 @@ impl<'a> MethodGen<'a> {
 struct ServiceGen<'a> {
     proto: &'a ServiceDescriptorProto,
     methods: Vec<MethodGen<'a>>,
+    opts: &'a GenOptions,
+    doc: Option<&'a str>,
 }
 
 impl<'a> ServiceGen<'a> {
     fn new(
         proto: &'a ServiceDescriptorProto,
-        file: &FileDescriptorProto,
+        index: usize,
+        file: &'a FileDescriptorProto,
         root_scope: &'a RootScope,
+        opts: &'a GenOptions,
     ) -> ServiceGen<'a> {
+        let path = [FILE_SERVICE_FIELD, index as i32];
+        let doc = |path: &[i32]| {
+            if opts.doc_comments {
+                leading_comments(file, path)
+            } else {
+                None
+            }
+        };
         let service_path = if file.get_package().is_empty() {
             format!("/{}", proto.get_name())
         } else {
@@ -551,17 +716,25 @@ impl<'a> ServiceGen<'a> {
         let methods = proto
             .get_method()
             .iter()
-            .map(|m| {
+            .enumerate()
+            .map(|(i, m)| {
                 MethodGen::new(
                     m,
                     util::to_camel_case(proto.get_name()),
                     service_path.clone(),
                     root_scope,
+                    opts,
+                    doc(&[path[0], path[1], SERVICE_METHOD_FIELD, i as i32]),
                 )
             })
             .collect();
 
-        ServiceGen { proto, methods }
+        ServiceGen {
+            proto,
+            methods,
+            opts,
+            doc: doc(&path),
+        }
     }
 
     fn service_name(&self) -> String {
@@ -572,26 +745,42 @@ impl<'a> ServiceGen<'a> {
         format!("{}Client", self.service_name())
     }
 
+    fn mock_name(&self) -> String {
+        format!("{}Mock", self.service_name())
+    }
+
     fn write_client(&self, w: &mut CodeWriter) {
+        let vis = &self.opts.visibility;
+        w.doc_comment(self.doc);
         w.write_line("#[derive(Clone)]");
-        w.pub_struct(&self.client_name(), |w| {
+        w.vis_struct(vis, &self.client_name(), |w| {
             w.field_decl("client", "::grpcio::Client");
         });
 
         w.write_line("");
 
         w.impl_self_block(&self.client_name(), |w| {
-            w.pub_fn("new(channel: ::grpcio::Channel) -> Self", |w| {
+            w.vis_fn(vis, "new(channel: ::grpcio::Channel) -> Self", |w| {
                 w.expr_block(&self.client_name(), |w| {
                     w.field_entry("client", "::grpcio::Client::new(channel)");
                 });
             });
 
+            w.write_line("");
+            w.vis_fn(
+                vis,
+                "set_channel(&self, channel: ::grpcio::Channel)",
+                |w| {
+                    w.write_line("self.client.swap_channel(channel);");
+                },
+            );
+
             for method in &self.methods {
                 w.write_line("");
                 method.write_client(w);
             }
-            w.pub_fn(
+            w.vis_fn(
+                vis,
                 "spawn<F>(&self, f: F) where F: ::std::future::Future<Output = ()> + Send + 'static",
                 |w| {
                     w.write_line("self.client.spawn(f)");
@@ -601,7 +790,9 @@ impl<'a> ServiceGen<'a> {
     }
 
     fn write_server(&self, w: &mut CodeWriter) {
-        w.pub_trait(&self.service_name(), |w| {
+        let vis = &self.opts.visibility;
+        w.doc_comment(self.doc);
+        w.vis_trait(vis, &self.service_name(), |w| {
             for method in &self.methods {
                 method.write_service(w);
             }
@@ -615,7 +806,7 @@ impl<'a> ServiceGen<'a> {
             self.service_name(),
             fq_grpc("Service")
         );
-        w.pub_fn(&s, |w| {
+        w.vis_fn(vis, &s, |w| {
             w.write_line("let mut builder = ::grpcio::ServiceBuilder::new();");
             for method in &self.methods[0..self.methods.len() - 1] {
                 w.write_line("let mut instance = s.clone();");
@@ -639,18 +830,48 @@ impl<'a> ServiceGen<'a> {
         }
     }
 
+    fn write_mock(&self, w: &mut CodeWriter) {
+        w.write_line(&format!(
+            "/// A mock of `{}` that delegates calls to the handlers set, returns",
+            self.service_name()
+        ));
+        w.write_line("/// `UNIMPLEMENTED` if the handler is not set.");
+        w.write_line("#[derive(Clone, Default)]");
+        w.vis_struct(&self.opts.visibility, &self.mock_name(), |w| {
+            for method in &self.methods {
+                method.write_mock_field(w);
+            }
+        });
+
+        w.write_line("");
+
+        w.expr_block(
+            &format!("impl {} for {}", self.service_name(), self.mock_name()),
+            |w| {
+                for method in &self.methods {
+                    method.write_mock(w);
+                }
+            },
+        );
+    }
+
     fn write(&self, w: &mut CodeWriter) {
         self.write_method_definitions(w);
         w.write_line("");
         self.write_client(w);
         w.write_line("");
         self.write_server(w);
+        if self.opts.mock {
+            w.write_line("");
+            self.write_mock(w);
+        }
     }
 }
 
 fn gen_file(
     file: &FileDescriptorProto,
     root_scope: &RootScope,
+    opts: &GenOptions,
 ) -> Option<compiler_plugin::GenResult> {
     if file.get_service().is_empty() {
         return None;
@@ -663,14 +884,14 @@ fn gen_file(
         let mut w = CodeWriter::new(&mut v);
         w.write_generated();
 
-        for service in file.get_service() {
+        for (i, service) in file.get_service().iter().enumerate() {
             w.write_line("");
-            ServiceGen::new(service, file, root_scope).write(&mut w);
+            ServiceGen::new(service, i, file, root_scope, opts).write(&mut w);
         }
     }
 
     Some(compiler_plugin::GenResult {
-        name: base + "_grpc.rs",
+        name: format!("{}{}.rs", base, opts.file_suffix),
         content: v,
     })
 }
@@ -678,6 +899,15 @@ fn gen_file(
 pub fn gen(
     file_descriptors: &[FileDescriptorProto],
     files_to_generate: &[String],
+) -> Vec<compiler_plugin::GenResult> {
+    gen_with_options(file_descriptors, files_to_generate, &GenOptions::default())
+}
+
+/// Same as [`gen`], but generate code as specified by `opts`.
+pub fn gen_with_options(
+    file_descriptors: &[FileDescriptorProto],
+    files_to_generate: &[String],
+    opts: &GenOptions,
 ) -> Vec<compiler_plugin::GenResult> {
     let files_map: HashMap<&str, &FileDescriptorProto> =
         file_descriptors.iter().map(|f| (f.get_name(), f)).collect();
@@ -693,12 +923,60 @@ pub fn gen(
             continue;
         }
 
-        results.extend(gen_file(file, &root_scope).into_iter());
+        results.extend(gen_file(file, &root_scope, opts).into_iter());
     }
 
     results
 }
 
 pub fn protoc_gen_grpc_rust_main() {
-    compiler_plugin::plugin_main(gen);
+    compiler_plugin::plugin_main_2(|req| {
+        let opts = match GenOptions::from_parameter(req.parameter) {
+            Ok(opts) => opts,
+            Err(e) => {
+                eprintln!("invalid grpc_opt: {}", e);
+                process::exit(1);
+            }
+        };
+        gen_with_options(req.file_descriptors, req.files_to_generate, &opts)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeWriter;
+
+    fn doc_comment(doc: &str) -> String {
+        let mut v = Vec::new();
+        CodeWriter::new(&mut v).doc_comment(Some(doc));
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn test_doc_comment() {
+        assert_eq!(doc_comment(" Say hello.\n"), "/// Say hello.\n");
+
+        let doc = " Say hello.\n\n Example:\n\n     client.say_hello(&req)?;\n\n     done();\n Returns a greeting.\n";
+        let expect = "/// Say hello.
+///
+/// Example:
+///
+/// ```text
+///     client.say_hello(&req)?;
+///
+///     done();
+/// ```
+/// Returns a greeting.
+";
+        assert_eq!(doc_comment(doc), expect);
+
+        // Indented lines in a paragraph are not code blocks.
+        let doc = " A list:\n     continued\n";
+        assert_eq!(doc_comment(doc), "/// A list:\n///     continued\n");
+
+        assert_eq!(
+            doc_comment(" Trailing code:\n\n \tx\n"),
+            "/// Trailing code:\n///\n/// ```text\n/// \tx\n/// ```\n"
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 #[cfg(feature = "protobuf-codec")]
 pub mod codegen;
+#[cfg(feature = "protobuf-codec")]
+pub mod options;
 #[cfg(feature = "prost-codec")]
 pub mod prost_codegen;
 
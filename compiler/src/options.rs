@@ -0,0 +1,193 @@
+// Copyright 2023 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Options that control how `grpc_rust_plugin` generates code.
+//!
+//! Options can be passed by `--grpc_opt=key=value,key=value`, or stored in a config
+//! file and referenced by `--grpc_opt=config=path/to/grpc.toml`. Options passed on the
+//! command line take precedence over the ones in the config file. A config file is a
+//! TOML document with top level keys only, for example:
+//!
+//! ```toml
+//! # Generate `foo.grpc.rs` instead of `foo_grpc.rs`.
+//! file_suffix = ".grpc"
+//! visibility = "pub(crate)"
+//! mock = true
+//! doc_comments = true
+//! ```
+
+use std::fs;
+
+/// Options for generating gRPC code.
+///
+/// More options may be added in the future, so create it by [`GenOptions::default`]
+/// and then set the fields.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct GenOptions {
+    /// Suffix appended to the proto file name for the generated file, `_grpc` by default.
+    pub file_suffix: String,
+    /// Visibility of the generated items, `pub` by default.
+    pub visibility: String,
+    /// Whether to generate a mock implementation for every service.
+    pub mock: bool,
+    /// Whether to copy comments in proto files to generated code as doc comments.
+    pub doc_comments: bool,
+}
+
+impl Default for GenOptions {
+    fn default() -> GenOptions {
+        GenOptions {
+            file_suffix: "_grpc".to_owned(),
+            visibility: "pub".to_owned(),
+            mock: false,
+            doc_comments: false,
+        }
+    }
+}
+
+impl GenOptions {
+    /// Parse options from the parameter passed by protoc.
+    ///
+    /// If `config` is specified, the config file is loaded first.
+    pub fn from_parameter(parameter: &str) -> Result<GenOptions, String> {
+        let pairs = parameter
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|p| match p.split_once('=') {
+                Some((k, v)) => (k.trim(), v.trim()),
+                // Flags without value are treated as switches.
+                None => (p, "true"),
+            })
+            .collect::<Vec<_>>();
+
+        let mut opts = GenOptions::default();
+        for (k, v) in &pairs {
+            if *k == "config" {
+                let content = fs::read_to_string(v)
+                    .map_err(|e| format!("failed to read config file {}: {}", v, e))?;
+                opts = GenOptions::from_toml(&content)?;
+            }
+        }
+        for (k, v) in pairs {
+            if k != "config" {
+                opts.set(k, v)?;
+            }
+        }
+        Ok(opts)
+    }
+
+    /// Parse options from a TOML document.
+    ///
+    /// All options are top level keys, and their values should be strings or booleans.
+    pub fn from_toml(content: &str) -> Result<GenOptions, String> {
+        let table = match content.parse::<toml::Value>() {
+            Ok(toml::Value::Table(t)) => t,
+            Ok(_) => return Err("config should be a table".to_owned()),
+            Err(e) => return Err(format!("invalid config: {}", e)),
+        };
+        let mut opts = GenOptions::default();
+        for (k, v) in &table {
+            match v {
+                toml::Value::String(s) => opts.set(k, s)?,
+                toml::Value::Boolean(b) => opts.set(k, if *b { "true" } else { "false" })?,
+                v => {
+                    return Err(format!(
+                        "{} should be a string or a boolean, got {}",
+                        k,
+                        v.type_str()
+                    ))
+                }
+            }
+        }
+        Ok(opts)
+    }
+
+    fn set(&mut self, key: &str, val: &str) -> Result<(), String> {
+        match key {
+            "file_suffix" => self.file_suffix = val.to_owned(),
+            "visibility" => match val {
+                "pub" | "pub(crate)" | "pub(super)" => self.visibility = val.to_owned(),
+                _ => return Err(format!("unsupported visibility {}", val)),
+            },
+            "mock" => self.mock = parse_bool(key, val)?,
+            "doc_comments" => self.doc_comments = parse_bool(key, val)?,
+            "serde_derive" => {
+                return Err(
+                    "serde derives are generated with messages, pass it to --rust_opt instead"
+                        .to_owned(),
+                )
+            }
+            _ => return Err(format!("unknown option {}", key)),
+        }
+        Ok(())
+    }
+}
+
+fn parse_bool(key: &str, val: &str) -> Result<bool, String> {
+    match val {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(format!("{} expects a boolean, got {}", key, val)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GenOptions;
+
+    #[test]
+    fn test_parse_parameter() {
+        assert_eq!(
+            GenOptions::from_parameter("").unwrap(),
+            GenOptions::default()
+        );
+
+        let opts =
+            GenOptions::from_parameter("visibility=pub(crate), mock,doc_comments=false").unwrap();
+        assert_eq!(opts.visibility, "pub(crate)");
+        assert!(opts.mock);
+        assert!(!opts.doc_comments);
+        assert_eq!(opts.file_suffix, "_grpc");
+
+        GenOptions::from_parameter("visibility=private").unwrap_err();
+        GenOptions::from_parameter("mock=yes").unwrap_err();
+        GenOptions::from_parameter("unknown=1").unwrap_err();
+        GenOptions::from_parameter("serde_derive").unwrap_err();
+        GenOptions::from_parameter("config=/non/exist/grpc.toml").unwrap_err();
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let content = r#"
+# Comment line.
+file_suffix = ".grpc#\u0031" # trailing comment
+visibility = 'pub(crate)'
+mock = true
+"#;
+        let opts = GenOptions::from_toml(content).unwrap();
+        assert_eq!(opts.file_suffix, ".grpc#1");
+        assert_eq!(opts.visibility, "pub(crate)");
+        assert!(opts.mock);
+        assert!(!opts.doc_comments);
+
+        GenOptions::from_toml("mock").unwrap_err();
+        GenOptions::from_toml("mock = 1").unwrap_err();
+        GenOptions::from_toml("[grpc]\nmock = true").unwrap_err();
+    }
+
+    #[test]
+    fn test_parameter_override_config() {
+        // Use a per process name, so concurrent test runs don't race on the file.
+        let path = std::env::temp_dir().join(format!(
+            "grpcio-compiler-test-options-{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "mock = true\nvisibility = \"pub(crate)\"\n").unwrap();
+        let opts = GenOptions::from_parameter(&format!("visibility=pub,config={}", path.display()))
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(opts.mock);
+        assert_eq!(opts.visibility, "pub");
+    }
+}
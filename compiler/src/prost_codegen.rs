@@ -173,6 +173,7 @@ fn generate_client(service: &Service, buf: &mut String) {
     buf.push_str(&client_name);
     buf.push_str(" {\n");
     generate_ctor(&client_name, buf);
+    generate_set_channel(buf);
     generate_client_methods(service, buf);
     generate_spawn(buf);
     buf.push_str("}\n")
@@ -185,6 +186,14 @@ fn generate_ctor(client_name: &str, buf: &mut String) {
     buf.push_str("}\n");
 }
 
+fn generate_set_channel(buf: &mut String) {
+    buf.push_str(
+        "pub fn set_channel(&self, channel: ::grpcio::Channel) { \
+         self.client.swap_channel(channel); \
+         }\n",
+    );
+}
+
 fn generate_client_methods(service: &Service, buf: &mut String) {
     for method in &service.methods {
         generate_client_method(&service.name, method, buf);
@@ -0,0 +1,93 @@
+// Generated by `cargo xtask channel-args`, don't edit it manually.
+
+use crate::bindings::*;
+
+/// All channel argument keys defined in gRPC C Core headers.
+pub const KNOWN_CHANNEL_ARGS: &[&[u8]] = &[
+    GRPC_ARG_ALLOW_REUSEPORT,
+    GRPC_ARG_AUTHORIZATION_POLICY_PROVIDER,
+    GRPC_ARG_CHANNEL_ID,
+    GRPC_ARG_CHANNEL_POOL_DOMAIN,
+    GRPC_ARG_CLIENT_IDLE_TIMEOUT_MS,
+    GRPC_ARG_DEFAULT_AUTHORITY,
+    GRPC_ARG_DISABLE_CLIENT_AUTHORITY_FILTER,
+    GRPC_ARG_DNS_ARES_QUERY_TIMEOUT_MS,
+    GRPC_ARG_DNS_ENABLE_SRV_QUERIES,
+    GRPC_ARG_DNS_MIN_TIME_BETWEEN_RESOLUTIONS_MS,
+    GRPC_ARG_ENABLE_CENSUS,
+    GRPC_ARG_ENABLE_CHANNELZ,
+    GRPC_ARG_ENABLE_DEADLINE_CHECKS,
+    GRPC_ARG_ENABLE_HTTP_PROXY,
+    GRPC_ARG_ENABLE_LOAD_REPORTING,
+    GRPC_ARG_ENABLE_PER_MESSAGE_COMPRESSION,
+    GRPC_ARG_ENABLE_PER_MESSAGE_DECOMPRESSION,
+    GRPC_ARG_ENABLE_RETRIES,
+    GRPC_ARG_EXPAND_WILDCARD_ADDRS,
+    GRPC_ARG_EXPERIMENTAL_ENABLE_HEDGING,
+    GRPC_ARG_GRPCLB_CALL_TIMEOUT_MS,
+    GRPC_ARG_GRPCLB_FALLBACK_TIMEOUT_MS,
+    GRPC_ARG_GZIP_COMPRESSION_LEVEL,
+    GRPC_ARG_HTTP2_BDP_PROBE,
+    GRPC_ARG_HTTP2_ENABLE_TRUE_BINARY,
+    GRPC_ARG_HTTP2_HPACK_TABLE_SIZE_DECODER,
+    GRPC_ARG_HTTP2_HPACK_TABLE_SIZE_ENCODER,
+    GRPC_ARG_HTTP2_INITIAL_SEQUENCE_NUMBER,
+    GRPC_ARG_HTTP2_MAX_FRAME_SIZE,
+    GRPC_ARG_HTTP2_MAX_PINGS_WITHOUT_DATA,
+    GRPC_ARG_HTTP2_MAX_PING_STRIKES,
+    GRPC_ARG_HTTP2_MIN_RECV_PING_INTERVAL_WITHOUT_DATA_MS,
+    GRPC_ARG_HTTP2_MIN_SENT_PING_INTERVAL_WITHOUT_DATA_MS,
+    GRPC_ARG_HTTP2_SCHEME,
+    GRPC_ARG_HTTP2_STREAM_LOOKAHEAD_BYTES,
+    GRPC_ARG_HTTP2_WRITE_BUFFER_SIZE,
+    GRPC_ARG_HTTP_PROXY,
+    GRPC_ARG_INHIBIT_HEALTH_CHECKING,
+    GRPC_ARG_INITIAL_RECONNECT_BACKOFF_MS,
+    GRPC_ARG_KEEPALIVE_PERMIT_WITHOUT_CALLS,
+    GRPC_ARG_KEEPALIVE_TIMEOUT_MS,
+    GRPC_ARG_KEEPALIVE_TIME_MS,
+    GRPC_ARG_LB_POLICY_NAME,
+    GRPC_ARG_MAX_CHANNEL_TRACE_EVENT_MEMORY_PER_NODE,
+    GRPC_ARG_MAX_CONCURRENT_STREAMS,
+    GRPC_ARG_MAX_CONNECTION_AGE_GRACE_MS,
+    GRPC_ARG_MAX_CONNECTION_AGE_MS,
+    GRPC_ARG_MAX_CONNECTION_IDLE_MS,
+    GRPC_ARG_MAX_MESSAGE_LENGTH,
+    GRPC_ARG_MAX_METADATA_SIZE,
+    GRPC_ARG_MAX_RECEIVE_MESSAGE_LENGTH,
+    GRPC_ARG_MAX_RECONNECT_BACKOFF_MS,
+    GRPC_ARG_MAX_SEND_MESSAGE_LENGTH,
+    GRPC_ARG_MINIMAL_STACK,
+    GRPC_ARG_MIN_MESSAGE_SIZE_TO_COMPRESS,
+    GRPC_ARG_MIN_RECONNECT_BACKOFF_MS,
+    GRPC_ARG_MOBILE_LOG_CONTEXT,
+    GRPC_ARG_OPTIMIZATION_TARGET,
+    GRPC_ARG_PER_RPC_RETRY_BUFFER_SIZE,
+    GRPC_ARG_PRIMARY_USER_AGENT_STRING,
+    GRPC_ARG_PRIORITY_FAILOVER_TIMEOUT_MS,
+    GRPC_ARG_RESOURCE_QUOTA,
+    GRPC_ARG_SECONDARY_USER_AGENT_STRING,
+    GRPC_ARG_SERVER_CONFIG_CHANGE_DRAIN_GRACE_TIME_MS,
+    GRPC_ARG_SERVER_HANDSHAKE_TIMEOUT_MS,
+    GRPC_ARG_SERVICE_CONFIG,
+    GRPC_ARG_SERVICE_CONFIG_DISABLE_RESOLUTION,
+    GRPC_ARG_SOCKET_FACTORY,
+    GRPC_ARG_SOCKET_MUTATOR,
+    GRPC_ARG_SURFACE_USER_AGENT,
+    GRPC_ARG_TCP_MAX_READ_CHUNK_SIZE,
+    GRPC_ARG_TCP_MIN_READ_CHUNK_SIZE,
+    GRPC_ARG_TCP_READ_CHUNK_SIZE,
+    GRPC_ARG_TCP_TX_ZEROCOPY_ENABLED,
+    GRPC_ARG_TCP_TX_ZEROCOPY_MAX_SIMULT_SENDS,
+    GRPC_ARG_TCP_TX_ZEROCOPY_SEND_BYTES_THRESHOLD,
+    GRPC_ARG_TEST_ONLY_DO_NOT_USE_IN_PROD_XDS_BOOTSTRAP_CONFIG,
+    GRPC_ARG_TSI_MAX_FRAME_SIZE,
+    GRPC_ARG_USE_CRONET_PACKET_COALESCING,
+    GRPC_ARG_USE_LOCAL_SUBCHANNEL_POOL,
+    GRPC_ARG_WORKAROUND_CRONET_COMPRESSION,
+    GRPC_COMPRESSION_CHANNEL_DEFAULT_ALGORITHM,
+    GRPC_COMPRESSION_CHANNEL_DEFAULT_LEVEL,
+    GRPC_COMPRESSION_CHANNEL_ENABLED_ALGORITHMS_BITSET,
+    GRPC_SSL_SESSION_CACHE_ARG,
+    GRPC_SSL_TARGET_NAME_OVERRIDE_ARG,
+];
@@ -7,7 +7,9 @@
 mod bindings {
     include!(env!("BINDING_PATH"));
 }
+mod channel_args;
 mod grpc_wrap;
 
 pub use bindings::*;
+pub use channel_args::*;
 pub use grpc_wrap::*;
@@ -59,6 +59,10 @@ impl HealthClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn check_opt(
         &self,
         req: &super::health::HealthCheckRequest,
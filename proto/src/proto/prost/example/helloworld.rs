@@ -32,6 +32,9 @@ impl GreeterClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn say_hello_opt(
         &self,
         req: &HelloRequest,
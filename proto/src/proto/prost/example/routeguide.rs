@@ -120,6 +120,9 @@ impl RouteGuideClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn get_feature_opt(
         &self,
         req: &Point,
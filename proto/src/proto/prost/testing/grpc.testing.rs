@@ -760,6 +760,9 @@ impl BenchmarkServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn unary_call_opt(
         &self,
         req: &SimpleRequest,
@@ -994,6 +997,9 @@ impl WorkerServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn run_server_opt(
         &self,
         opt: ::grpcio::CallOption,
@@ -1164,6 +1170,9 @@ impl ReportQpsScenarioServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn report_scenario_opt(
         &self,
         req: &ScenarioResult,
@@ -1343,6 +1352,9 @@ impl TestServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn empty_call_opt(
         &self,
         req: &Empty,
@@ -1649,6 +1661,9 @@ impl UnimplementedServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn unimplemented_call_opt(
         &self,
         req: &Empty,
@@ -1736,6 +1751,9 @@ impl ReconnectServiceClient {
             client: ::grpcio::Client::new(channel),
         }
     }
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
     pub fn start_opt(
         &self,
         req: &ReconnectParams,
@@ -43,6 +43,10 @@ impl GreeterClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn say_hello_opt(
         &self,
         req: &super::helloworld::HelloRequest,
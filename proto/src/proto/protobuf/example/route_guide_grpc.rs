@@ -91,6 +91,10 @@ impl RouteGuideClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn get_feature_opt(
         &self,
         req: &super::route_guide::Point,
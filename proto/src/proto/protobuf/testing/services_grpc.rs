@@ -107,6 +107,10 @@ impl BenchmarkServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn unary_call_opt(
         &self,
         req: &super::messages::SimpleRequest,
@@ -373,6 +377,10 @@ impl WorkerServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn run_server_opt(
         &self,
         opt: ::grpcio::CallOption,
@@ -572,6 +580,10 @@ impl ReportQpsScenarioServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn report_scenario_opt(
         &self,
         req: &super::control::ScenarioResult,
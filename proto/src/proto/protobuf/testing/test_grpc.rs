@@ -153,6 +153,10 @@ impl TestServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn empty_call_opt(
         &self,
         req: &super::empty::Empty,
@@ -503,6 +507,10 @@ impl UnimplementedServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn unimplemented_call_opt(
         &self,
         req: &super::empty::Empty,
@@ -609,6 +617,10 @@ impl ReconnectServiceClient {
         }
     }
 
+    pub fn set_channel(&self, channel: ::grpcio::Channel) {
+        self.client.swap_channel(channel);
+    }
+
     pub fn start_opt(
         &self,
         req: &super::messages::ReconnectParams,
@@ -15,12 +15,12 @@ use parking_lot::Mutex;
 
 use super::{ShareCall, ShareCallHolder, SinkBase, WriteFlags};
 use crate::buf::GrpcSlice;
-use crate::call::{check_run, Call, MessageReader, Method};
+use crate::call::{check_run, Call, MessageReader, Method, RpcStatus, RpcStatusCode};
 use crate::channel::Channel;
 use crate::codec::{DeserializeFn, SerializeFn};
 use crate::error::{Error, Result};
 use crate::metadata::{Metadata, UnownedMetadata};
-use crate::task::{BatchFuture, BatchType};
+use crate::task::{BatchFuture, BatchType, Delay};
 
 /// Update the flag bit in res.
 #[inline]
@@ -36,6 +36,7 @@ pub fn change_flag(res: &mut u32, flag: u32, set: bool) {
 #[derive(Clone, Default)]
 pub struct CallOption {
     timeout: Option<Duration>,
+    message_timeout: Option<Duration>,
     write_flags: WriteFlags,
     call_flags: u32,
     headers: Option<Metadata>,
@@ -69,6 +70,26 @@ impl CallOption {
         self.timeout
     }
 
+    /// Set a timeout for waiting each message in a streaming response.
+    ///
+    /// Unlike [`timeout`](#method.timeout) which limits the whole call, this limits
+    /// the time that a streaming receiver can be kept waiting for the next message.
+    /// If the server doesn't send anything within the timeout, the call is canceled
+    /// and the receiver yields a `DEADLINE_EXCEEDED` error. The timer starts when the
+    /// receiver begins waiting for the next message, so it's useful for long-lived
+    /// subscription streams that should not hang forever on a silent server.
+    ///
+    /// Only server streaming and duplex streaming calls respect the option.
+    pub fn message_timeout(mut self, timeout: Duration) -> CallOption {
+        self.message_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the per message timeout.
+    pub fn get_message_timeout(&self) -> Option<Duration> {
+        self.message_timeout
+    }
+
     /// Set the headers to be sent with the call.
     pub fn headers(mut self, meta: Metadata) -> CallOption {
         self.headers = Some(meta);
@@ -163,6 +184,7 @@ impl Call {
             cq_f,
             method.resp_de(),
             headers_f,
+            opt.message_timeout,
         ))
     }
 
@@ -190,7 +212,8 @@ impl Call {
 
         let share_call = Arc::new(Mutex::new(ShareCall::new(call, cq_f)));
         let sink = ClientDuplexSender::new(share_call.clone(), method.req_ser(), opt.call_flags);
-        let recv = ClientDuplexReceiver::new(share_call, method.resp_de(), headers_f);
+        let recv =
+            ClientDuplexReceiver::new(share_call, method.resp_de(), headers_f, opt.message_timeout);
         Ok((sink, recv))
     }
 }
@@ -535,10 +558,17 @@ struct ResponseStreamImpl<H, T> {
     finished: bool,
     resp_de: DeserializeFn<T>,
     headers_f: FutureOrValue<BatchFuture, UnownedMetadata>,
+    // Timeout for waiting each message and the timer to track it.
+    msg_timeout: Option<(Duration, Delay)>,
 }
 
 impl<H: ShareCallHolder + Unpin, T> ResponseStreamImpl<H, T> {
-    fn new(call: H, resp_de: DeserializeFn<T>, headers_f: BatchFuture) -> ResponseStreamImpl<H, T> {
+    fn new(
+        call: H,
+        resp_de: DeserializeFn<T>,
+        headers_f: BatchFuture,
+        msg_timeout: Option<Duration>,
+    ) -> ResponseStreamImpl<H, T> {
         ResponseStreamImpl {
             call,
             msg_f: None,
@@ -546,9 +576,34 @@ impl<H: ShareCallHolder + Unpin, T> ResponseStreamImpl<H, T> {
             finished: false,
             resp_de,
             headers_f: FutureOrValue::Future(headers_f),
+            msg_timeout: msg_timeout.map(|t| (t, Delay::new())),
         }
     }
 
+    /// Check if the server has been silent for too long.
+    ///
+    /// Should only be called when the pending message is not ready yet.
+    fn poll_msg_timeout(&mut self, cx: &mut Context) -> Poll<Option<Result<T>>> {
+        let timeout = match &mut self.msg_timeout {
+            Some((timeout, delay)) => {
+                if !delay.is_armed() {
+                    delay.reset(*timeout);
+                }
+                if delay.poll_elapsed(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                delay.clear();
+                *timeout
+            }
+            None => return Poll::Pending,
+        };
+        self.cancel();
+        Poll::Ready(Some(Err(Error::RpcFailure(RpcStatus::with_message(
+            RpcStatusCode::DEADLINE_EXCEEDED,
+            format!("no message received within {:?}", timeout),
+        )))))
+    }
+
     fn cancel(&mut self) {
         self.call.call(|c| c.call.cancel())
     }
@@ -568,7 +623,13 @@ impl<H: ShareCallHolder + Unpin, T> ResponseStreamImpl<H, T> {
         loop {
             if !self.read_done {
                 if let Some(msg_f) = &mut self.msg_f {
-                    let batch_result = ready!(Pin::new(msg_f).poll(cx)?);
+                    let batch_result = match Pin::new(msg_f).poll(cx)? {
+                        Poll::Ready(res) => res,
+                        Poll::Pending => return self.poll_msg_timeout(cx),
+                    };
+                    if let Some((_, delay)) = &mut self.msg_timeout {
+                        delay.clear();
+                    }
                     bytes = batch_result.message_reader;
                     if bytes.is_none() {
                         self.read_done = true;
@@ -626,10 +687,11 @@ impl<Resp> ClientSStreamReceiver<Resp> {
         finish_f: BatchFuture,
         de: DeserializeFn<Resp>,
         headers_f: BatchFuture,
+        msg_timeout: Option<Duration>,
     ) -> ClientSStreamReceiver<Resp> {
         let share_call = ShareCall::new(call, finish_f);
         ClientSStreamReceiver {
-            imp: ResponseStreamImpl::new(share_call, de, headers_f),
+            imp: ResponseStreamImpl::new(share_call, de, headers_f, msg_timeout),
         }
     }
 
@@ -670,9 +732,10 @@ impl<Resp> ClientDuplexReceiver<Resp> {
         call: Arc<Mutex<ShareCall>>,
         de: DeserializeFn<Resp>,
         headers_f: BatchFuture,
+        msg_timeout: Option<Duration>,
     ) -> ClientDuplexReceiver<Resp> {
         ClientDuplexReceiver {
-            imp: ResponseStreamImpl::new(call, de, headers_f),
+            imp: ResponseStreamImpl::new(call, de, headers_f, msg_timeout),
         }
     }
 
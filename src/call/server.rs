@@ -256,10 +256,50 @@ impl UnaryRequestContext {
     }
 }
 
+// Adapter methods shared by `RequestStream` and its adapters.
+macro_rules! request_stream_adapter_methods {
+    () => {
+        /// Transform every message with `f`, errors are passed through as is.
+        pub fn map_msg<M, U, G: FnMut(M) -> U>(self, f: G) -> RequestStreamMap<Self, G>
+        where
+            Self: Stream<Item = Result<M>>,
+        {
+            RequestStreamMap { stream: self, f }
+        }
+
+        /// Skip messages that don't match the predicate `f`, errors are passed
+        /// through as is.
+        pub fn filter_msg<M, G: FnMut(&M) -> bool>(self, f: G) -> RequestStreamFilter<Self, G>
+        where
+            Self: Stream<Item = Result<M>>,
+        {
+            RequestStreamFilter { stream: self, f }
+        }
+
+        /// Transform every message with a fallible `f`.
+        ///
+        /// Errors returned by `f` are yielded as is, the stream can still be polled
+        /// after an error.
+        pub fn try_map_msg<M, U, G: FnMut(M) -> Result<U>>(
+            self,
+            f: G,
+        ) -> RequestStreamTryMap<Self, G>
+        where
+            Self: Stream<Item = Result<M>>,
+        {
+            RequestStreamTryMap { stream: self, f }
+        }
+    };
+}
+
 /// A stream for client a streaming call and a duplex streaming call.
 ///
 /// The corresponding RPC will be canceled if the stream did not
 /// finish before dropping.
+///
+/// Messages can be transformed by [`map_msg`](#method.map_msg),
+/// [`filter_msg`](#method.filter_msg) and [`try_map_msg`](#method.try_map_msg) without
+/// handling errors of the stream.
 #[must_use = "if unused the RequestStream may immediately cancel the RPC"]
 pub struct RequestStream<T> {
     call: Arc<Mutex<ShareCall>>,
@@ -275,6 +315,8 @@ impl<T> RequestStream<T> {
             de,
         }
     }
+
+    request_stream_adapter_methods!();
 }
 
 impl<T> Stream for RequestStream<T> {
@@ -302,6 +344,128 @@ impl<T> Drop for RequestStream<T> {
     }
 }
 
+// A macro helper to implement the adapters of `RequestStream`.
+macro_rules! impl_request_stream_adapter {
+    ($(#[$attr:meta])* $t:ident) => {
+        $(#[$attr])*
+        #[must_use = "if unused the RequestStream may immediately cancel the RPC"]
+        pub struct $t<S, F> {
+            stream: S,
+            f: F,
+        }
+
+        impl<S, F> $t<S, F> {
+            /// Get a reference to the underlying stream.
+            pub fn get_ref(&self) -> &S {
+                &self.stream
+            }
+
+            /// Consume the adapter and return the underlying stream.
+            pub fn into_inner(self) -> S {
+                self.stream
+            }
+
+            request_stream_adapter_methods!();
+        }
+
+        // `f` is never pinned.
+        impl<S: Unpin, F> Unpin for $t<S, F> {}
+    };
+}
+
+impl_request_stream_adapter!(
+    /// Stream for the [`map_msg`](RequestStream::map_msg) method.
+    RequestStreamMap
+);
+impl_request_stream_adapter!(
+    /// Stream for the [`filter_msg`](RequestStream::filter_msg) method.
+    RequestStreamFilter
+);
+impl_request_stream_adapter!(
+    /// Stream for the [`try_map_msg`](RequestStream::try_map_msg) method.
+    RequestStreamTryMap
+);
+
+impl<S, M, U, F> Stream for RequestStreamMap<S, F>
+where
+    S: Stream<Item = Result<M>> + Unpin,
+    F: FnMut(M) -> U,
+{
+    type Item = Result<U>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<U>>> {
+        let t = &mut *self;
+        let res = ready!(Pin::new(&mut t.stream).poll_next(cx));
+        Poll::Ready(res.map(|r| r.map(&mut t.f)))
+    }
+}
+
+impl<S, M, F> Stream for RequestStreamFilter<S, F>
+where
+    S: Stream<Item = Result<M>> + Unpin,
+    F: FnMut(&M) -> bool,
+{
+    type Item = Result<M>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<M>>> {
+        let t = &mut *self;
+        loop {
+            match ready!(Pin::new(&mut t.stream).poll_next(cx)) {
+                Some(Ok(msg)) if !(t.f)(&msg) => continue,
+                res => return Poll::Ready(res),
+            }
+        }
+    }
+}
+
+impl<S, M, U, F> Stream for RequestStreamTryMap<S, F>
+where
+    S: Stream<Item = Result<M>> + Unpin,
+    F: FnMut(M) -> Result<U>,
+{
+    type Item = Result<U>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<U>>> {
+        let t = &mut *self;
+        let res = ready!(Pin::new(&mut t.stream).poll_next(cx));
+        Poll::Ready(res.map(|r| r.and_then(&mut t.f)))
+    }
+}
+
+// Adapter methods shared by server side streaming sinks and their adapters.
+macro_rules! sink_adapter_methods {
+    () => {
+        /// Transform every message with `f` before sending it.
+        ///
+        /// Use `into_inner` to get the original sink back, for example, to fail the call.
+        pub fn map_msg<M, U, G: FnMut(U) -> M>(self, f: G) -> SinkMap<Self, G>
+        where
+            Self: Sink<(M, WriteFlags)>,
+        {
+            SinkMap { sink: self, f }
+        }
+
+        /// Drop messages that don't match the predicate `f` instead of sending them.
+        pub fn filter_msg<M, G: FnMut(&M) -> bool>(self, f: G) -> SinkFilter<Self, G>
+        where
+            Self: Sink<(M, WriteFlags)>,
+        {
+            SinkFilter { sink: self, f }
+        }
+
+        /// Transform every message with a fallible `f` before sending it.
+        ///
+        /// Errors returned by `f` are returned by `start_send`, and the message is
+        /// not sent.
+        pub fn try_map_msg<M, U, G: FnMut(U) -> Result<M>>(self, f: G) -> SinkTryMap<Self, G>
+        where
+            Self: Sink<(M, WriteFlags)>,
+        {
+            SinkTryMap { sink: self, f }
+        }
+    };
+}
+
 /// A helper macro used to implement server side unary sink.
 /// Not using generic here because we don't need to expose
 /// `CallHolder` or `Call` to caller.
@@ -495,6 +659,8 @@ macro_rules! impl_stream_sink {
                 self.status = status;
             }
 
+            sink_adapter_methods!();
+
             pub fn fail(mut self, status: RpcStatus) -> $ft {
                 assert!(self.flush_f.is_none());
                 let send_metadata = self.base.send_metadata;
@@ -641,6 +807,145 @@ impl_stream_sink!(
     Arc<Mutex<ShareCall>>
 );
 
+// A macro helper to implement the adapters of server side streaming sinks.
+macro_rules! impl_sink_adapter {
+    ($(#[$attr:meta])* $t:ident) => {
+        $(#[$attr])*
+        #[must_use = "if unused the sink may immediately cancel the RPC"]
+        pub struct $t<S, F> {
+            sink: S,
+            f: F,
+        }
+
+        impl<S, F> $t<S, F> {
+            /// Get a reference to the underlying sink.
+            pub fn get_ref(&self) -> &S {
+                &self.sink
+            }
+
+            /// Get a mutable reference to the underlying sink.
+            pub fn get_mut(&mut self) -> &mut S {
+                &mut self.sink
+            }
+
+            /// Consume the adapter and return the underlying sink.
+            pub fn into_inner(self) -> S {
+                self.sink
+            }
+
+            sink_adapter_methods!();
+        }
+
+        // `f` is never pinned.
+        impl<S: Unpin, F> Unpin for $t<S, F> {}
+    };
+}
+
+impl_sink_adapter!(
+    /// Sink for the `map_msg` method of [`ServerStreamingSink`] and [`DuplexSink`].
+    SinkMap
+);
+impl_sink_adapter!(
+    /// Sink for the `filter_msg` method of [`ServerStreamingSink`] and [`DuplexSink`].
+    SinkFilter
+);
+impl_sink_adapter!(
+    /// Sink for the `try_map_msg` method of [`ServerStreamingSink`] and [`DuplexSink`].
+    SinkTryMap
+);
+
+impl<S, F, T, U> Sink<(U, WriteFlags)> for SinkMap<S, F>
+where
+    S: Sink<(T, WriteFlags), Error = Error> + Unpin,
+    F: FnMut(U) -> T,
+{
+    type Error = Error;
+
+    #[inline]
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_ready(cx)
+    }
+
+    #[inline]
+    fn start_send(mut self: Pin<&mut Self>, (msg, flags): (U, WriteFlags)) -> Result<()> {
+        let t = &mut *self;
+        Pin::new(&mut t.sink).start_send(((t.f)(msg), flags))
+    }
+
+    #[inline]
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_close(cx)
+    }
+}
+
+impl<S, F, T> Sink<(T, WriteFlags)> for SinkFilter<S, F>
+where
+    S: Sink<(T, WriteFlags), Error = Error> + Unpin,
+    F: FnMut(&T) -> bool,
+{
+    type Error = Error;
+
+    #[inline]
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_ready(cx)
+    }
+
+    #[inline]
+    fn start_send(mut self: Pin<&mut Self>, (msg, flags): (T, WriteFlags)) -> Result<()> {
+        let t = &mut *self;
+        if (t.f)(&msg) {
+            Pin::new(&mut t.sink).start_send((msg, flags))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_close(cx)
+    }
+}
+
+impl<S, F, T, U> Sink<(U, WriteFlags)> for SinkTryMap<S, F>
+where
+    S: Sink<(T, WriteFlags), Error = Error> + Unpin,
+    F: FnMut(U) -> Result<T>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_ready(cx)
+    }
+
+    #[inline]
+    fn start_send(mut self: Pin<&mut Self>, (msg, flags): (U, WriteFlags)) -> Result<()> {
+        let t = &mut *self;
+        let msg = (t.f)(msg)?;
+        Pin::new(&mut t.sink).start_send((msg, flags))
+    }
+
+    #[inline]
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<()>> {
+        Pin::new(&mut self.sink).poll_close(cx)
+    }
+}
+
 /// A context for rpc handling.
 pub struct RpcContext<'a> {
     ctx: RequestContext,
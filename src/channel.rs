@@ -4,13 +4,14 @@ use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use std::{cmp, i32, ptr};
 
 use crate::{
-    grpc_sys::{self, gpr_timespec, grpc_arg_pointer_vtable, grpc_channel, grpc_channel_args},
+    grpc_sys::{self, gpr_timespec, grpc_channel, grpc_channel_args},
     Deadline,
 };
 use libc::{self, c_char, c_int};
@@ -46,10 +47,26 @@ fn dur_to_ms(dur: Duration) -> i32 {
     cmp::min(i32::MAX as u64, millis) as i32
 }
 
-enum Options {
+/// The value of a channel argument.
+#[derive(Clone)]
+pub enum ArgValue {
+    /// An integer argument, booleans are passed as 0 or 1.
     Integer(i32),
+    /// A string argument, it's copied when building the channel.
     String(CString),
-    Pointer(ResourceQuota, *const grpc_arg_pointer_vtable),
+    /// Resource quota is the only pointer argument that can be passed safely, it's only
+    /// accepted for the `grpc.resource_quota` key.
+    ResourceQuota(ResourceQuota),
+}
+
+impl fmt::Debug for ArgValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgValue::Integer(val) => write!(f, "{}", val),
+            ArgValue::String(val) => write!(f, "{:?}", val),
+            ArgValue::ResourceQuota(_) => write!(f, "ResourceQuota"),
+        }
+    }
 }
 
 /// The optimization target for a [`Channel`].
@@ -72,7 +89,7 @@ pub enum LbPolicy {
 /// [`Channel`] factory in order to configure the properties.
 pub struct ChannelBuilder {
     env: Arc<Environment>,
-    options: HashMap<Cow<'static, [u8]>, Options>,
+    options: HashMap<Cow<'static, [u8]>, ArgValue>,
     credentials: Option<ChannelCredentials>,
 }
 
@@ -91,19 +108,17 @@ impl ChannelBuilder {
         let authority = CString::new(authority).unwrap();
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_DEFAULT_AUTHORITY),
-            Options::String(authority),
+            ArgValue::String(authority),
         );
         self
     }
 
     /// Set resource quota by consuming a ResourceQuota
     pub fn set_resource_quota(mut self, quota: ResourceQuota) -> ChannelBuilder {
-        unsafe {
-            self.options.insert(
-                Cow::Borrowed(grpcio_sys::GRPC_ARG_RESOURCE_QUOTA),
-                Options::Pointer(quota, grpc_sys::grpc_resource_quota_arg_vtable()),
-            );
-        }
+        self.options.insert(
+            Cow::Borrowed(grpcio_sys::GRPC_ARG_RESOURCE_QUOTA),
+            ArgValue::ResourceQuota(quota),
+        );
         self
     }
 
@@ -111,7 +126,7 @@ impl ChannelBuilder {
     pub fn max_concurrent_stream(mut self, num: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_MAX_CONCURRENT_STREAMS),
-            Options::Integer(num),
+            ArgValue::Integer(num),
         );
         self
     }
@@ -120,7 +135,7 @@ impl ChannelBuilder {
     pub fn max_receive_message_len(mut self, len: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_MAX_RECEIVE_MESSAGE_LENGTH),
-            Options::Integer(len),
+            ArgValue::Integer(len),
         );
         self
     }
@@ -129,7 +144,7 @@ impl ChannelBuilder {
     pub fn max_send_message_len(mut self, len: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_MAX_SEND_MESSAGE_LENGTH),
-            Options::Integer(len),
+            ArgValue::Integer(len),
         );
         self
     }
@@ -138,7 +153,29 @@ impl ChannelBuilder {
     pub fn max_reconnect_backoff(mut self, backoff: Duration) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_MAX_RECONNECT_BACKOFF_MS),
-            Options::Integer(dur_to_ms(backoff)),
+            ArgValue::Integer(dur_to_ms(backoff)),
+        );
+        self
+    }
+
+    /// Set the minimum time a single connection attempt is allowed to take.
+    ///
+    /// An attempt covers both TCP connect and handshake on client side. gRPC C Core sets
+    /// its deadline to the later one of the next reconnect backoff and now plus this
+    /// value, so it's a lower bound rather than a cap, and an attempt can take longer
+    /// when the backoff is large. Defaults to 20 seconds.
+    ///
+    /// Client side handshake can't be bounded separately from connect, gRPC C Core 1.44
+    /// has no argument for it. Neither does it tell the two phases apart when reporting
+    /// errors, calls fail with `UNAVAILABLE` and "failed to connect to all addresses"
+    /// either way. The handshake timeout of server is set by
+    /// [`ServerBuilder::handshake_timeout`].
+    ///
+    /// [`ServerBuilder::handshake_timeout`]: crate::ServerBuilder::handshake_timeout
+    pub fn min_connect_timeout(mut self, timeout: Duration) -> ChannelBuilder {
+        self.options.insert(
+            Cow::Borrowed(grpcio_sys::GRPC_ARG_MIN_RECONNECT_BACKOFF_MS),
+            ArgValue::Integer(dur_to_ms(timeout)),
         );
         self
     }
@@ -147,7 +184,7 @@ impl ChannelBuilder {
     pub fn initial_reconnect_backoff(mut self, backoff: Duration) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_INITIAL_RECONNECT_BACKOFF_MS),
-            Options::Integer(dur_to_ms(backoff)),
+            ArgValue::Integer(dur_to_ms(backoff)),
         );
         self
     }
@@ -156,7 +193,7 @@ impl ChannelBuilder {
     pub fn https_initial_seq_number(mut self, number: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_INITIAL_SEQUENCE_NUMBER),
-            Options::Integer(number),
+            ArgValue::Integer(number),
         );
         self
     }
@@ -166,7 +203,7 @@ impl ChannelBuilder {
     pub fn stream_initial_window_size(mut self, window_size: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_STREAM_LOOKAHEAD_BYTES),
-            Options::Integer(window_size),
+            ArgValue::Integer(window_size),
         );
         self
     }
@@ -177,7 +214,7 @@ impl ChannelBuilder {
         let agent_string = format_user_agent_string(agent);
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_PRIMARY_USER_AGENT_STRING),
-            Options::String(agent_string),
+            ArgValue::String(agent_string),
         );
         self
     }
@@ -187,7 +224,7 @@ impl ChannelBuilder {
         let opt = if reuse { 1 } else { 0 };
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_ALLOW_REUSEPORT),
-            Options::Integer(opt),
+            ArgValue::Integer(opt),
         );
         self
     }
@@ -196,7 +233,7 @@ impl ChannelBuilder {
     pub fn tcp_read_chunk_size(mut self, bytes: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_TCP_READ_CHUNK_SIZE),
-            Options::Integer(bytes),
+            ArgValue::Integer(bytes),
         );
         self
     }
@@ -205,7 +242,7 @@ impl ChannelBuilder {
     pub fn tcp_min_read_chunk_size(mut self, bytes: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_TCP_MIN_READ_CHUNK_SIZE),
-            Options::Integer(bytes),
+            ArgValue::Integer(bytes),
         );
         self
     }
@@ -214,7 +251,7 @@ impl ChannelBuilder {
     pub fn tcp_max_read_chunk_size(mut self, bytes: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_TCP_MAX_READ_CHUNK_SIZE),
-            Options::Integer(bytes),
+            ArgValue::Integer(bytes),
         );
         self
     }
@@ -224,7 +261,7 @@ impl ChannelBuilder {
     pub fn http2_write_buffer_size(mut self, size: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_WRITE_BUFFER_SIZE),
-            Options::Integer(size),
+            ArgValue::Integer(size),
         );
         self
     }
@@ -236,7 +273,7 @@ impl ChannelBuilder {
     pub fn http2_max_frame_size(mut self, size: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_MAX_FRAME_SIZE),
-            Options::Integer(size),
+            ArgValue::Integer(size),
         );
         self
     }
@@ -245,7 +282,7 @@ impl ChannelBuilder {
     pub fn http2_bdp_probe(mut self, enable: bool) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_BDP_PROBE),
-            Options::Integer(enable as i32),
+            ArgValue::Integer(enable as i32),
         );
         self
     }
@@ -258,7 +295,7 @@ impl ChannelBuilder {
     ) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_MIN_SENT_PING_INTERVAL_WITHOUT_DATA_MS),
-            Options::Integer(dur_to_ms(interval)),
+            ArgValue::Integer(dur_to_ms(interval)),
         );
         self
     }
@@ -271,7 +308,7 @@ impl ChannelBuilder {
     ) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_MIN_RECV_PING_INTERVAL_WITHOUT_DATA_MS),
-            Options::Integer(dur_to_ms(interval)),
+            ArgValue::Integer(dur_to_ms(interval)),
         );
         self
     }
@@ -282,7 +319,7 @@ impl ChannelBuilder {
     pub fn http2_max_pings_without_data(mut self, num: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_MAX_PINGS_WITHOUT_DATA),
-            Options::Integer(num),
+            ArgValue::Integer(num),
         );
         self
     }
@@ -293,7 +330,7 @@ impl ChannelBuilder {
     pub fn http2_max_ping_strikes(mut self, num: i32) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_HTTP2_MAX_PING_STRIKES),
-            Options::Integer(num),
+            ArgValue::Integer(num),
         );
         self
     }
@@ -302,7 +339,7 @@ impl ChannelBuilder {
     pub fn enable_http_proxy(mut self, num: bool) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_ENABLE_HTTP_PROXY),
-            Options::Integer(num as i32),
+            ArgValue::Integer(num as i32),
         );
         self
     }
@@ -311,7 +348,7 @@ impl ChannelBuilder {
     pub fn default_compression_algorithm(mut self, algo: CompressionAlgorithms) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_COMPRESSION_CHANNEL_DEFAULT_ALGORITHM),
-            Options::Integer(algo as i32),
+            ArgValue::Integer(algo as i32),
         );
         self
     }
@@ -321,7 +358,7 @@ impl ChannelBuilder {
     pub fn default_gzip_compression_level(mut self, level: usize) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_GZIP_COMPRESSION_LEVEL),
-            Options::Integer(level as i32),
+            ArgValue::Integer(level as i32),
         );
         self
     }
@@ -334,7 +371,7 @@ impl ChannelBuilder {
     ) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_MIN_MESSAGE_SIZE_TO_COMPRESS),
-            Options::Integer(lower_bound as i32),
+            ArgValue::Integer(lower_bound as i32),
         );
         self
     }
@@ -343,7 +380,7 @@ impl ChannelBuilder {
     pub fn default_compression_level(mut self, level: CompressionLevel) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_COMPRESSION_CHANNEL_DEFAULT_LEVEL),
-            Options::Integer(level as i32),
+            ArgValue::Integer(level as i32),
         );
         self
     }
@@ -353,7 +390,7 @@ impl ChannelBuilder {
     pub fn keepalive_time(mut self, timeout: Duration) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_KEEPALIVE_TIME_MS),
-            Options::Integer(dur_to_ms(timeout)),
+            ArgValue::Integer(dur_to_ms(timeout)),
         );
         self
     }
@@ -363,7 +400,7 @@ impl ChannelBuilder {
     pub fn keepalive_timeout(mut self, timeout: Duration) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_KEEPALIVE_TIMEOUT_MS),
-            Options::Integer(dur_to_ms(timeout)),
+            ArgValue::Integer(dur_to_ms(timeout)),
         );
         self
     }
@@ -372,7 +409,7 @@ impl ChannelBuilder {
     pub fn keepalive_permit_without_calls(mut self, allow: bool) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_KEEPALIVE_PERMIT_WITHOUT_CALLS),
-            Options::Integer(allow as i32),
+            ArgValue::Integer(allow as i32),
         );
         self
     }
@@ -387,7 +424,7 @@ impl ChannelBuilder {
         };
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_OPTIMIZATION_TARGET),
-            Options::String(val.unwrap()),
+            ArgValue::String(val.unwrap()),
         );
         self
     }
@@ -402,7 +439,7 @@ impl ChannelBuilder {
         };
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_LB_POLICY_NAME),
-            Options::String(val.unwrap()),
+            ArgValue::String(val.unwrap()),
         );
         self
     }
@@ -413,7 +450,7 @@ impl ChannelBuilder {
     pub fn use_local_subchannel_pool(mut self, enable: bool) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_USE_LOCAL_SUBCHANNEL_POOL),
-            Options::Integer(enable as i32),
+            ArgValue::Integer(enable as i32),
         );
         self
     }
@@ -426,72 +463,63 @@ impl ChannelBuilder {
     pub fn enable_retry(mut self, enable: bool) -> ChannelBuilder {
         self.options.insert(
             Cow::Borrowed(grpcio_sys::GRPC_ARG_ENABLE_RETRIES),
-            Options::Integer(enable as i32),
+            ArgValue::Integer(enable as i32),
         );
         self
     }
 
-    /// Set a raw integer configuration.
+    /// Set a raw channel argument.
     ///
-    /// This method is only for bench usage, users should use the encapsulated API instead.
-    #[doc(hidden)]
-    pub fn raw_cfg_int(mut self, key: CString, val: i32) -> ChannelBuilder {
-        self.options
-            .insert(Cow::Owned(key.into_bytes_with_nul()), Options::Integer(val));
+    /// It's an escape hatch for arguments that are not encapsulated yet, users should
+    /// prefer the typed API whenever possible. A warning is logged if the key is not
+    /// defined by gRPC C Core, as such argument is usually ignored silently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` contains a NUL byte, or if `val` is [`ArgValue::ResourceQuota`] but
+    /// `key` is not `grpc.resource_quota`, as gRPC C Core would treat the quota as whatever
+    /// the key points to. Prefer [`set_resource_quota`](#method.set_resource_quota).
+    pub fn raw_arg<S: Into<Vec<u8>>>(mut self, key: S, val: ArgValue) -> ChannelBuilder {
+        let key = CString::new(key)
+            .expect("channel argument key should not contain NUL")
+            .into_bytes_with_nul();
+        if let ArgValue::ResourceQuota(_) = val {
+            assert!(
+                key == grpcio_sys::GRPC_ARG_RESOURCE_QUOTA,
+                "resource quota can't be set for {:?}",
+                CStr::from_bytes_with_nul(&key).unwrap()
+            );
+        }
+        if !grpc_sys::KNOWN_CHANNEL_ARGS.contains(&key.as_slice()) {
+            warn!(
+                "unknown channel argument {:?} = {:?}",
+                CStr::from_bytes_with_nul(&key).unwrap(),
+                val
+            );
+        }
+        self.options.insert(Cow::Owned(key), val);
         self
     }
 
-    /// Set a raw string configuration.
+    /// Get all the arguments that have been set, including the ones set by typed API.
     ///
-    /// This method is only for bench usage, users should use the encapsulated API instead.
-    #[doc(hidden)]
-    pub fn raw_cfg_string(mut self, key: CString, val: CString) -> ChannelBuilder {
+    /// Order of the arguments is not specified.
+    pub fn args(&self) -> impl Iterator<Item = (&CStr, &ArgValue)> {
         self.options
-            .insert(Cow::Owned(key.into_bytes_with_nul()), Options::String(val));
-        self
+            .iter()
+            .map(|(k, v)| (CStr::from_bytes_with_nul(k).unwrap(), v))
     }
 
     /// Build `ChannelArgs` from the current configuration.
-    #[allow(clippy::useless_conversion)]
-    #[allow(clippy::cmp_owned)]
     pub fn build_args(&self) -> ChannelArgs {
-        let args = unsafe { grpc_sys::grpcwrap_channel_args_create(self.options.len()) };
-        for (i, (k, v)) in self.options.iter().enumerate() {
-            let key = k.as_ptr() as *const c_char;
-            match *v {
-                Options::Integer(val) => unsafe {
-                    // On most modern compiler and architect, c_int is the same as i32,
-                    // panic directly to simplify signature.
-                    assert!(
-                        val <= i32::from(libc::INT_MAX) && val >= i32::from(libc::INT_MIN),
-                        "{} is out of range for {:?}",
-                        val,
-                        CStr::from_bytes_with_nul(k).unwrap()
-                    );
-                    grpc_sys::grpcwrap_channel_args_set_integer(args, i, key, val as c_int)
-                },
-                Options::String(ref val) => unsafe {
-                    grpc_sys::grpcwrap_channel_args_set_string(args, i, key, val.as_ptr())
-                },
-                Options::Pointer(ref quota, vtable) => unsafe {
-                    grpc_sys::grpcwrap_channel_args_set_pointer_vtable(
-                        args,
-                        i,
-                        key,
-                        quota.get_ptr() as _,
-                        vtable,
-                    )
-                },
-            }
-        }
-        ChannelArgs { args }
+        ChannelArgs::new(self.options.clone())
     }
 
     fn prepare_connect_args(&mut self) -> ChannelArgs {
         if let Entry::Vacant(e) = self.options.entry(Cow::Borrowed(
             grpcio_sys::GRPC_ARG_PRIMARY_USER_AGENT_STRING,
         )) {
-            e.insert(Options::String(format_user_agent_string("")));
+            e.insert(ArgValue::String(format_user_agent_string("")));
         }
         self.build_args()
     }
@@ -545,7 +573,7 @@ mod secure_channel {
 
     use crate::ChannelCredentials;
 
-    use super::{ChannelBuilder, Options};
+    use super::{ArgValue, ChannelBuilder};
 
     const OPT_SSL_TARGET_NAME_OVERRIDE: &[u8] = b"grpc.ssl_target_name_override\0";
 
@@ -559,7 +587,7 @@ mod secure_channel {
             let target = CString::new(target).unwrap();
             self.options.insert(
                 Cow::Borrowed(OPT_SSL_TARGET_NAME_OVERRIDE),
-                Options::String(target),
+                ArgValue::String(target),
             );
             self
         }
@@ -574,14 +602,76 @@ mod secure_channel {
 
 pub struct ChannelArgs {
     args: *mut grpc_channel_args,
+    // Arguments `args` is built from, kept for merging.
+    options: HashMap<Cow<'static, [u8]>, ArgValue>,
 }
 
 impl ChannelArgs {
+    #[allow(clippy::useless_conversion)]
+    #[allow(clippy::cmp_owned)]
+    fn new(options: HashMap<Cow<'static, [u8]>, ArgValue>) -> ChannelArgs {
+        let args = unsafe { grpc_sys::grpcwrap_channel_args_create(options.len()) };
+        for (i, (k, v)) in options.iter().enumerate() {
+            let key = k.as_ptr() as *const c_char;
+            match *v {
+                ArgValue::Integer(val) => unsafe {
+                    // On most modern compiler and architect, c_int is the same as i32,
+                    // panic directly to simplify signature.
+                    assert!(
+                        val <= i32::from(libc::INT_MAX) && val >= i32::from(libc::INT_MIN),
+                        "{} is out of range for {:?}",
+                        val,
+                        CStr::from_bytes_with_nul(k).unwrap()
+                    );
+                    grpc_sys::grpcwrap_channel_args_set_integer(args, i, key, val as c_int)
+                },
+                ArgValue::String(ref val) => unsafe {
+                    grpc_sys::grpcwrap_channel_args_set_string(args, i, key, val.as_ptr())
+                },
+                ArgValue::ResourceQuota(ref quota) => unsafe {
+                    grpc_sys::grpcwrap_channel_args_set_pointer_vtable(
+                        args,
+                        i,
+                        key,
+                        quota.get_ptr() as _,
+                        grpc_sys::grpc_resource_quota_arg_vtable(),
+                    )
+                },
+            }
+        }
+        ChannelArgs { args, options }
+    }
+
+    /// Build arguments that only contain the handshake timeout of server.
+    pub(crate) fn server_handshake_timeout(timeout: Duration) -> ChannelArgs {
+        let mut options: HashMap<Cow<'static, [u8]>, ArgValue> = HashMap::with_capacity(1);
+        options.insert(
+            Cow::Borrowed(grpcio_sys::GRPC_ARG_SERVER_HANDSHAKE_TIMEOUT_MS),
+            ArgValue::Integer(dur_to_ms(timeout)),
+        );
+        ChannelArgs::new(options)
+    }
+
+    /// Merge `other` into the arguments, values in `other` take precedence.
+    pub(crate) fn merge(&self, other: &ChannelArgs) -> ChannelArgs {
+        let mut options = self.options.clone();
+        options.extend(other.options.iter().map(|(k, v)| (k.clone(), v.clone())));
+        ChannelArgs::new(options)
+    }
+
     pub fn as_ptr(&self) -> *const grpc_channel_args {
         self.args
     }
 }
 
+// `args` is never mutated once built and only read by gRPC C Core, which copies it when
+// creating a channel or server. Pointers in it are either strings owned by `args` or
+// resource quotas, which are referenced by `args` and are thread safe in gRPC C Core.
+// `options` only contains owned strings and resource quotas.
+#[allow(clippy::non_send_fields_in_send_ty)]
+unsafe impl Send for ChannelArgs {}
+unsafe impl Sync for ChannelArgs {}
+
 impl Drop for ChannelArgs {
     fn drop(&mut self) {
         unsafe { grpc_sys::grpcwrap_channel_args_destroy(self.args) }
@@ -767,12 +857,44 @@ impl Channel {
 }
 
 #[cfg(test)]
-#[cfg(feature = "nightly")]
 mod tests {
     use crate::env::Environment;
-    use crate::ChannelBuilder;
+    use crate::{ArgValue, ChannelBuilder, ResourceQuota};
+    use std::collections::HashMap;
+    use std::ffi::CString;
     use std::sync::Arc;
 
+    #[test]
+    fn test_raw_arg() {
+        let env = Arc::new(Environment::new(1));
+        let cb = ChannelBuilder::new(env)
+            .max_send_message_len(1024)
+            .raw_arg("grpc.max_concurrent_streams", ArgValue::Integer(10))
+            .raw_arg(
+                "grpc.unknown_arg",
+                ArgValue::String(CString::new("v").unwrap()),
+            );
+        let args: HashMap<_, _> = cb
+            .args()
+            .map(|(k, v)| (k.to_str().unwrap().to_owned(), format!("{:?}", v)))
+            .collect();
+        assert_eq!(args.len(), 3);
+        assert_eq!(args["grpc.max_send_message_length"], "1024");
+        assert_eq!(args["grpc.max_concurrent_streams"], "10");
+        assert_eq!(args["grpc.unknown_arg"], "\"v\"");
+        cb.build_args();
+    }
+
+    #[test]
+    #[should_panic(expected = "resource quota can't be set")]
+    fn test_raw_arg_resource_quota_key() {
+        let env = Arc::new(Environment::new(1));
+        ChannelBuilder::new(env).raw_arg(
+            "grpc.socket_mutator",
+            ArgValue::ResourceQuota(ResourceQuota::new(None)),
+        );
+    }
+
     #[test]
     #[cfg(feature = "nightly")]
     fn test_grpc_min_message_size_to_compress() {
@@ -1,6 +1,10 @@
 // Copyright 2019 TiKV Project Authors. Licensed under Apache-2.0.
 
 use std::future::Future;
+use std::mem;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
 
 use crate::call::client::{
     CallOption, ClientCStreamReceiver, ClientCStreamSender, ClientDuplexReceiver,
@@ -13,19 +17,47 @@ use crate::task::Executor;
 use crate::task::Kicker;
 use futures_executor::block_on;
 
-/// A generic client for making RPC calls.
-#[derive(Clone)]
-pub struct Client {
+struct ClientChannel {
     channel: Channel,
     // Used to kick its completion queue.
     kicker: Kicker,
 }
 
+impl ClientChannel {
+    fn new(channel: Channel) -> ClientChannel {
+        let kicker = channel.create_kicker().unwrap();
+        ClientChannel { channel, kicker }
+    }
+}
+
+/// A generic client for making RPC calls.
+///
+/// Clones of a client share the same channel, see [`swap_channel`](#method.swap_channel).
+#[derive(Clone)]
+pub struct Client {
+    channel: Arc<RwLock<ClientChannel>>,
+}
+
 impl Client {
     /// Initialize a new [`Client`].
     pub fn new(channel: Channel) -> Client {
-        let kicker = channel.create_kicker().unwrap();
-        Client { channel, kicker }
+        Client {
+            channel: Arc::new(RwLock::new(ClientChannel::new(channel))),
+        }
+    }
+
+    /// Replace the channel used by the client and all its clones, and return the
+    /// old one.
+    ///
+    /// Calls created afterwards use the new channel, while in-flight calls keep running
+    /// on the old channel until they finish, so it's safe to drop the returned channel
+    /// immediately. It's useful to point long-lived clients to a new endpoint or to
+    /// refresh their credentials. Create a new client instead if only one of the
+    /// clones should be changed.
+    pub fn swap_channel(&self, channel: Channel) -> Channel {
+        let new = ClientChannel::new(channel);
+        let old = mem::replace(&mut *self.channel.write(), new);
+        old.channel
     }
 
     /// Create a synchronized unary RPC call.
@@ -48,7 +80,7 @@ impl Client {
         req: &Req,
         opt: CallOption,
     ) -> Result<ClientUnaryReceiver<Resp>> {
-        Call::unary_async(&self.channel, method, req, opt)
+        Call::unary_async(&self.channel.read().channel, method, req, opt)
     }
 
     /// Create an asynchronized client streaming call.
@@ -59,7 +91,7 @@ impl Client {
         method: &Method<Req, Resp>,
         opt: CallOption,
     ) -> Result<(ClientCStreamSender<Req>, ClientCStreamReceiver<Resp>)> {
-        Call::client_streaming(&self.channel, method, opt)
+        Call::client_streaming(&self.channel.read().channel, method, opt)
     }
 
     /// Create an asynchronized server streaming call.
@@ -71,7 +103,7 @@ impl Client {
         req: &Req,
         opt: CallOption,
     ) -> Result<ClientSStreamReceiver<Resp>> {
-        Call::server_streaming(&self.channel, method, req, opt)
+        Call::server_streaming(&self.channel.read().channel, method, req, opt)
     }
 
     /// Create an asynchronized duplex streaming call.
@@ -84,7 +116,7 @@ impl Client {
         method: &Method<Req, Resp>,
         opt: CallOption,
     ) -> Result<(ClientDuplexSender<Req>, ClientDuplexReceiver<Resp>)> {
-        Call::duplex_streaming(&self.channel, method, opt)
+        Call::duplex_streaming(&self.channel.read().channel, method, opt)
     }
 
     /// Spawn the future into current gRPC poll thread.
@@ -95,7 +127,11 @@ impl Client {
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        let kicker = self.kicker.clone();
-        Executor::new(self.channel.cq()).spawn(f, kicker)
+        // The future is polled immediately, don't hold the lock.
+        let (cq, kicker) = {
+            let ch = self.channel.read();
+            (ch.channel.cq().clone(), ch.kicker.clone())
+        };
+        Executor::new(&cq).spawn(f, kicker)
     }
 }
@@ -32,6 +32,8 @@ pub enum Error {
     GoogleAuthenticationFailed,
     /// Invalid format of metadata.
     InvalidMetadata(String),
+    /// All replicas of a hedged call fail, errors are in the same order as the replicas.
+    HedgeFailure(Vec<Error>),
 }
 
 impl fmt::Display for Error {
@@ -0,0 +1,105 @@
+// Copyright 2023 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Hedged requests send the same request to several replicas and use whichever
+//! response comes first, which cuts the tail latency caused by a slow replica.
+//!
+//! Replicas are tried one by one with a stagger delay, so a request only costs
+//! more than one call when the first replica doesn't respond in time.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::future::poll_fn;
+
+use crate::call::client::ClientUnaryReceiver;
+use crate::error::{Error, Result};
+use crate::task::Delay;
+
+/// Calls that are still in flight, they are canceled on drop.
+struct InflightCalls<Resp> {
+    calls: Vec<(usize, ClientUnaryReceiver<Resp>)>,
+}
+
+impl<Resp> Drop for InflightCalls<Resp> {
+    fn drop(&mut self) {
+        for (_, call) in &mut self.calls {
+            call.cancel();
+        }
+    }
+}
+
+/// Send a unary request to the replicas and return the first successful response.
+///
+/// `call` starts the request on a replica, usually by calling the `*_async_opt` method
+/// of a generated client, so `replicas` can be clients, channels or anything `call`
+/// knows how to use. The request is sent to `replicas[0]` first. Whenever `stagger`
+/// elapses without a response, or all in-flight calls fail, it's sent to the next
+/// replica. Once a response is received, all the other in-flight calls are canceled,
+/// so are all the in-flight calls when the returned future is dropped.
+///
+/// Only use it for idempotent methods like reads, as more than one replica may
+/// handle the request. If all replicas fail, [`Error::HedgeFailure`] is returned
+/// with the error of each replica, in the same order as `replicas`.
+///
+/// ```ignore
+/// let resp = hedged_unary_call(
+///     &clients,
+///     |c: &GreeterClient| c.say_hello_async_opt(&req, CallOption::default()),
+///     Duration::from_millis(50),
+/// )
+/// .await?;
+/// ```
+pub async fn hedged_unary_call<T, Resp, F>(
+    replicas: &[T],
+    mut call: F,
+    stagger: Duration,
+) -> Result<Resp>
+where
+    Resp: Unpin,
+    F: FnMut(&T) -> Result<ClientUnaryReceiver<Resp>>,
+{
+    let mut inflight = InflightCalls { calls: Vec::new() };
+    let mut errors = Vec::with_capacity(replicas.len());
+    let mut delay = Delay::new();
+    let mut next = 0;
+    poll_fn(|cx: &mut Context| loop {
+        if next < replicas.len() && (inflight.calls.is_empty() || delay.poll_elapsed(cx).is_ready())
+        {
+            match call(&replicas[next]) {
+                Ok(c) => inflight.calls.push((next, c)),
+                Err(e) => errors.push((next, e)),
+            }
+            next += 1;
+            delay.reset(stagger);
+            continue;
+        }
+
+        let mut i = 0;
+        while i < inflight.calls.len() {
+            match Pin::new(&mut inflight.calls[i].1).poll(cx) {
+                Poll::Ready(Ok(resp)) => {
+                    // The call is finished, no need to cancel it.
+                    drop(inflight.calls.swap_remove(i));
+                    return Poll::Ready(Ok(resp));
+                }
+                Poll::Ready(Err(e)) => {
+                    let (idx, _) = inflight.calls.swap_remove(i);
+                    errors.push((idx, e));
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+
+        if !inflight.calls.is_empty() {
+            return Poll::Pending;
+        }
+        if next == replicas.len() {
+            errors.sort_by_key(|(idx, _)| *idx);
+            let errors = errors.drain(..).map(|(_, e)| e).collect();
+            return Poll::Ready(Err(Error::HedgeFailure(errors)));
+        }
+    })
+    .await
+}
@@ -39,6 +39,7 @@ mod codec;
 mod cq;
 mod env;
 mod error;
+mod hedge;
 mod log_util;
 mod metadata;
 mod quota;
@@ -53,13 +54,14 @@ pub use crate::call::client::{
 };
 pub use crate::call::server::{
     ClientStreamingSink, ClientStreamingSinkResult, Deadline, DuplexSink, DuplexSinkFailure,
-    RequestStream, RpcContext, ServerStreamingSink, ServerStreamingSinkFailure, UnarySink,
+    RequestStream, RequestStreamFilter, RequestStreamMap, RequestStreamTryMap, RpcContext,
+    ServerStreamingSink, ServerStreamingSinkFailure, SinkFilter, SinkMap, SinkTryMap, UnarySink,
     UnarySinkResult,
 };
 pub use crate::call::{MessageReader, Method, MethodType, RpcStatus, RpcStatusCode, WriteFlags};
 pub use crate::channel::{
-    Channel, ChannelBuilder, CompressionAlgorithms, CompressionLevel, ConnectivityState, LbPolicy,
-    OptTarget,
+    ArgValue, Channel, ChannelBuilder, CompressionAlgorithms, CompressionLevel, ConnectivityState,
+    LbPolicy, OptTarget,
 };
 pub use crate::client::Client;
 
@@ -72,12 +74,14 @@ pub use crate::auth_context::{AuthContext, AuthProperty, AuthPropertyIter};
 pub use crate::codec::{Marshaller, MAX_MESSAGE_SIZE};
 pub use crate::env::{EnvBuilder, Environment};
 pub use crate::error::{Error, Result};
+pub use crate::hedge::hedged_unary_call;
 pub use crate::log_util::redirect_log;
 pub use crate::metadata::{Metadata, MetadataBuilder, MetadataIter};
 pub use crate::quota::ResourceQuota;
 pub use crate::security::*;
 pub use crate::server::{
-    CheckResult, Server, ServerBuilder, ServerChecker, Service, ServiceBuilder, ShutdownFuture,
+    CheckResult, ListenerConfig, Server, ServerBuilder, ServerChecker, Service, ServiceBuilder,
+    ShutdownFuture,
 };
 
 /// A shortcut for implementing a service method by returning `UNIMPLEMENTED` status code.
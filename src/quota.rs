@@ -6,6 +6,8 @@ use std::ptr;
 
 /// ResourceQuota represents a bound on memory and thread usage by the gRPC.
 /// NOTE: The management of threads created in grpc-core don't use ResourceQuota.
+/// NOTE: gRPC C Core 1.44 exposes neither allocator hooks nor memory counters, so the
+/// memory it uses can only be bounded by the quota, not observed.
 /// TODO: Manage the poller threads created in grpc-rs with this ResourceQuota later.
 pub struct ResourceQuota {
     raw: *mut grpc_resource_quota,
@@ -40,6 +42,11 @@ impl ResourceQuota {
     }
 }
 
+// gRPC C Core reference counts resource quotas atomically and synchronizes resizing
+// internally.
+unsafe impl Send for ResourceQuota {}
+unsafe impl Sync for ResourceQuota {}
+
 impl Clone for ResourceQuota {
     fn clone(&self) -> Self {
         unsafe {
@@ -10,6 +10,7 @@ use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use crate::grpc_sys::{self, grpc_call_error, grpc_server};
 use futures_util::ready;
@@ -200,6 +201,7 @@ pub struct Service {
 pub struct ServerBuilder {
     env: Arc<Environment>,
     args: Option<ChannelArgs>,
+    handshake_timeout: Option<Duration>,
     slots_per_cq: usize,
     handlers: HashMap<&'static [u8], BoxHandler>,
     checkers: Vec<Box<dyn ServerChecker>>,
@@ -211,6 +213,7 @@ impl ServerBuilder {
         ServerBuilder {
             env,
             args: None,
+            handshake_timeout: None,
             slots_per_cq: DEFAULT_REQUEST_SLOTS_PER_CQ,
             handlers: HashMap::new(),
             checkers: Vec::new(),
@@ -223,6 +226,17 @@ impl ServerBuilder {
         self
     }
 
+    /// Set the timeout of the handshake, including TLS handshake, after a connection is
+    /// accepted. Defaults to 120 seconds.
+    ///
+    /// There is no equivalent for channels, see [`ChannelBuilder::min_connect_timeout`].
+    ///
+    /// [`ChannelBuilder::min_connect_timeout`]: crate::ChannelBuilder::min_connect_timeout
+    pub fn handshake_timeout(mut self, timeout: Duration) -> ServerBuilder {
+        self.handshake_timeout = Some(timeout);
+        self
+    }
+
     /// Set how many requests a completion queue can handle.
     pub fn requests_slot_per_cq(mut self, slots: usize) -> ServerBuilder {
         self.slots_per_cq = slots;
@@ -247,34 +261,93 @@ impl ServerBuilder {
 
     /// Finalize the [`ServerBuilder`] and build the [`Server`].
     pub fn build(self) -> Result<Server> {
-        let args = self
-            .args
-            .as_ref()
-            .map_or_else(ptr::null, ChannelArgs::as_ptr);
-        unsafe {
-            let server = grpc_sys::grpc_server_create(args, ptr::null_mut());
-            for cq in self.env.completion_queues() {
-                let cq_ref = cq.borrow()?;
-                grpc_sys::grpc_server_register_completion_queue(
-                    server,
-                    cq_ref.as_ptr(),
-                    ptr::null_mut(),
-                );
-            }
+        let args = with_handshake_timeout(self.args, self.handshake_timeout);
+        let core = ServerCore::new(&self.env, args.as_ref(), self.slots_per_cq)?;
+        Ok(Server {
+            env: self.env,
+            core,
+            listeners: Vec::new(),
+            args,
+            handlers: self.handlers,
+            checkers: self.checkers,
+            started: false,
+        })
+    }
+}
 
-            Ok(Server {
-                env: self.env,
-                core: Arc::new(ServerCore {
-                    server,
-                    creds: Mutex::new(Vec::new()),
-                    shutdown: AtomicBool::new(false),
-                    slots_per_cq: self.slots_per_cq,
-                }),
-                handlers: self.handlers,
-                checkers: self.checkers,
-            })
+/// Configuration of a listening address.
+///
+/// A listener can have its own credentials, channel arguments and checkers, so
+/// that, for example, an admin port on localhost can be served in plaintext while
+/// the public port requires mutual TLS.
+pub struct ListenerConfig {
+    addr: String,
+    creds: ServerCredentials,
+    args: Option<ChannelArgs>,
+    handshake_timeout: Option<Duration>,
+    checkers: Vec<Box<dyn ServerChecker>>,
+}
+
+impl ListenerConfig {
+    /// Initialize a new [`ListenerConfig`] for the given `addr` endpoint (eg, localhost:1234,
+    /// 192.168.1.1:31416, [::1]:27182, etc.).
+    ///
+    /// The listener is insecure by default.
+    pub fn new(addr: impl Into<String>) -> ListenerConfig {
+        ListenerConfig {
+            addr: addr.into(),
+            creds: ServerCredentials::insecure(),
+            args: None,
+            handshake_timeout: None,
+            checkers: Vec::new(),
         }
     }
+
+    /// Set the credentials used to accept connections.
+    pub fn credentials(mut self, creds: ServerCredentials) -> ListenerConfig {
+        self.creds = creds;
+        self
+    }
+
+    /// Set the configuration for connections accepted by the listener, like TCP
+    /// and HTTP/2 options.
+    ///
+    /// They are merged with the arguments set by [`ServerBuilder::channel_args`], and
+    /// take precedence when both set the same argument.
+    pub fn channel_args(mut self, args: ChannelArgs) -> ListenerConfig {
+        self.args = Some(args);
+        self
+    }
+
+    /// Set the handshake timeout of connections accepted by the listener, it takes
+    /// precedence over [`ServerBuilder::handshake_timeout`].
+    pub fn handshake_timeout(mut self, timeout: Duration) -> ListenerConfig {
+        self.handshake_timeout = Some(timeout);
+        self
+    }
+
+    /// Add a checker that only applies to calls accepted by the listener.
+    ///
+    /// The checkers are executed after the ones added by [`ServerBuilder::add_checker`].
+    pub fn add_checker<C: ServerChecker + 'static>(mut self, checker: C) -> ListenerConfig {
+        self.checkers.push(Box::new(checker));
+        self
+    }
+}
+
+/// Add the handshake timeout to `args` if it's set.
+fn with_handshake_timeout(
+    args: Option<ChannelArgs>,
+    timeout: Option<Duration>,
+) -> Option<ChannelArgs> {
+    let timeout = match timeout {
+        Some(timeout) => ChannelArgs::server_handshake_timeout(timeout),
+        None => return args,
+    };
+    match args {
+        Some(args) => Some(args.merge(&timeout)),
+        None => Some(timeout),
+    }
 }
 
 struct ServerCore {
@@ -290,9 +363,61 @@ impl Drop for ServerCore {
     }
 }
 
+impl ServerCore {
+    fn new(
+        env: &Environment,
+        args: Option<&ChannelArgs>,
+        slots_per_cq: usize,
+    ) -> Result<Arc<ServerCore>> {
+        let args_ptr = args.map_or_else(ptr::null, ChannelArgs::as_ptr);
+        unsafe {
+            let server = grpc_sys::grpc_server_create(args_ptr, ptr::null_mut());
+            let core = Arc::new(ServerCore {
+                server,
+                creds: Mutex::new(Vec::new()),
+                shutdown: AtomicBool::new(false),
+                slots_per_cq,
+            });
+            for cq in env.completion_queues() {
+                let cq_ref = cq.borrow()?;
+                grpc_sys::grpc_server_register_completion_queue(
+                    server,
+                    cq_ref.as_ptr(),
+                    ptr::null_mut(),
+                );
+            }
+            Ok(core)
+        }
+    }
+
+    fn add_listening_port(&self, addr: String, mut creds: ServerCredentials) -> Result<u16> {
+        // There is no Null in UTF-8 string.
+        let addr = CString::new(addr).unwrap();
+        let port = unsafe {
+            grpcio_sys::grpc_server_add_http2_port(
+                self.server,
+                addr.as_ptr() as _,
+                creds.as_mut_ptr(),
+            ) as u16
+        };
+        if port != 0 {
+            self.creds.lock().unwrap().push(creds);
+            Ok(port)
+        } else {
+            Err(Error::BindFail(addr))
+        }
+    }
+}
+
 unsafe impl Send for ServerCore {}
 unsafe impl Sync for ServerCore {}
 
+/// A server core that is created for listeners with dedicated configurations.
+struct ListenerCore {
+    core: Arc<ServerCore>,
+    checkers: Vec<Box<dyn ServerChecker>>,
+}
+
 pub type BoxHandler = Box<dyn CloneableHandler>;
 
 #[derive(Clone)]
@@ -353,17 +478,26 @@ pub fn request_call(ctx: RequestCallContext, cq: &CompletionQueue) {
 /// A `Future` that will resolve when shutdown completes.
 pub struct ShutdownFuture {
     /// `true` means the future finishes successfully.
-    cq_f: CqFuture<bool>,
+    cq_fs: Vec<CqFuture<bool>>,
+    failed: bool,
 }
 
 impl Future for ShutdownFuture {
     type Output = Result<()>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
-        match ready!(Pin::new(&mut self.cq_f).poll(cx)) {
-            Ok(true) => Poll::Ready(Ok(())),
-            Ok(false) => Poll::Ready(Err(Error::ShutdownFailed)),
-            Err(e) => unreachable!("action future should never resolve to error: {}", e),
+        while let Some(cq_f) = self.cq_fs.last_mut() {
+            match ready!(Pin::new(cq_f).poll(cx)) {
+                Ok(true) => {}
+                Ok(false) => self.failed = true,
+                Err(e) => unreachable!("action future should never resolve to error: {}", e),
+            }
+            self.cq_fs.pop();
+        }
+        if self.failed {
+            Poll::Ready(Err(Error::ShutdownFailed))
+        } else {
+            Poll::Ready(Ok(()))
         }
     }
 }
@@ -376,40 +510,63 @@ impl Future for ShutdownFuture {
 pub struct Server {
     env: Arc<Environment>,
     core: Arc<ServerCore>,
+    listeners: Vec<ListenerCore>,
+    args: Option<ChannelArgs>,
     handlers: HashMap<&'static [u8], BoxHandler>,
     checkers: Vec<Box<dyn ServerChecker>>,
+    started: bool,
 }
 
 impl Server {
+    fn cores(&self) -> impl Iterator<Item = &Arc<ServerCore>> {
+        std::iter::once(&self.core).chain(self.listeners.iter().map(|l| &l.core))
+    }
+
     /// Shutdown the server asynchronously.
     pub fn shutdown(&mut self) -> ShutdownFuture {
-        let (cq_f, prom) = CallTag::action_pair();
-        let prom_box = Box::new(prom);
-        let tag = Box::into_raw(prom_box);
-        unsafe {
-            // Since env still exists, no way can cq been shutdown.
-            let cq_ref = self.env.completion_queues()[0].borrow().unwrap();
-            grpc_sys::grpc_server_shutdown_and_notify(
-                self.core.server,
-                cq_ref.as_ptr(),
-                tag as *mut _,
-            )
+        // Since env still exists, no way can cq been shutdown.
+        let cq_ref = self.env.completion_queues()[0].borrow().unwrap();
+        let mut cq_fs = Vec::with_capacity(self.listeners.len() + 1);
+        for core in self.cores() {
+            let (cq_f, prom) = CallTag::action_pair();
+            let prom_box = Box::new(prom);
+            let tag = Box::into_raw(prom_box);
+            unsafe {
+                grpc_sys::grpc_server_shutdown_and_notify(
+                    core.server,
+                    cq_ref.as_ptr(),
+                    tag as *mut _,
+                )
+            }
+            core.shutdown.store(true, Ordering::SeqCst);
+            cq_fs.push(cq_f);
+        }
+        ShutdownFuture {
+            cq_fs,
+            failed: false,
         }
-        self.core.shutdown.store(true, Ordering::SeqCst);
-        ShutdownFuture { cq_f }
     }
 
     /// Cancel all in-progress calls.
     ///
     /// Only usable after shutdown.
     pub fn cancel_all_calls(&mut self) {
-        unsafe { grpc_sys::grpc_server_cancel_all_calls(self.core.server) }
+        for core in self.cores() {
+            unsafe { grpc_sys::grpc_server_cancel_all_calls(core.server) }
+        }
     }
 
     /// Start the server.
     pub fn start(&mut self) {
-        unsafe {
-            grpc_sys::grpc_server_start(self.core.server);
+        self.started = true;
+        let cores = std::iter::once((&self.core, &[][..]))
+            .chain(self.listeners.iter().map(|l| (&l.core, &l.checkers[..])));
+        for (core, listener_checkers) in cores {
+            unsafe {
+                grpc_sys::grpc_server_start(core.server);
+            }
+            let mut checkers = self.checkers.clone();
+            checkers.extend(listener_checkers.iter().cloned());
             for cq in self.env.completion_queues() {
                 // Handlers are Send and Clone, but not Sync. So we need to
                 // provide a replica for each completion queue.
@@ -419,11 +576,11 @@ impl Server {
                     .map(|(k, v)| (k.to_owned(), v.box_clone()))
                     .collect();
                 let rc = RequestCallContext {
-                    server: self.core.clone(),
+                    server: core.clone(),
                     registry: Arc::new(UnsafeCell::new(registry)),
-                    checkers: self.checkers.clone(),
+                    checkers: checkers.clone(),
                 };
-                for _ in 0..self.core.slots_per_cq {
+                for _ in 0..core.slots_per_cq {
                     request_call(rc.clone(), cq);
                 }
             }
@@ -441,23 +598,71 @@ impl Server {
     pub fn add_listening_port(
         &mut self,
         addr: impl Into<String>,
-        mut creds: ServerCredentials,
+        creds: ServerCredentials,
     ) -> Result<u16> {
-        // There is no Null in UTF-8 string.
-        let addr = CString::new(addr.into()).unwrap();
-        let port = unsafe {
-            grpcio_sys::grpc_server_add_http2_port(
-                self.core.server,
-                addr.as_ptr() as _,
-                creds.as_mut_ptr(),
-            ) as u16
-        };
-        if port != 0 {
-            self.core.creds.lock().unwrap().push(creds);
-            Ok(port)
-        } else {
-            Err(Error::BindFail(addr))
+        self.core.add_listening_port(addr.into(), creds)
+    }
+
+    /// Try binding the server with the given listener configuration.
+    ///
+    /// gRPC C Core only supports server wide channel arguments, so a listener with its
+    /// own channel arguments or checkers is served by a dedicated core server, which
+    /// shares services and completion queues with the others. Like the main one, a
+    /// dedicated core server keeps [`ServerBuilder::requests_slot_per_cq`] requests
+    /// pending on every completion queue, so each such listener adds that many request
+    /// contexts per completion queue. Listeners that only set credentials don't have
+    /// the cost.
+    ///
+    /// Channel arguments of the listener are merged with the server wide ones, so a
+    /// listener that only changes a TCP option still uses the resource quota and message
+    /// size limits of the server:
+    ///
+    /// ```ignore
+    /// let args = ChannelBuilder::new(env.clone())
+    ///     .set_resource_quota(quota)
+    ///     .max_receive_message_len(1 << 20)
+    ///     .build_args();
+    /// let mut server = ServerBuilder::new(env.clone())
+    ///     .channel_args(args)
+    ///     .register_service(service)
+    ///     .build()?;
+    /// // Connections to the port are limited by `quota` and the 1MiB receive limit too.
+    /// let args = ChannelBuilder::new(env).tcp_read_chunk_size(4096).build_args();
+    /// server.add_listener(ListenerConfig::new("127.0.0.1:0").channel_args(args))?;
+    /// ```
+    ///
+    /// It can be invoked multiple times. It fails after the server is started.
+    ///
+    /// # Return
+    ///
+    /// The bound port is returned on success.
+    pub fn add_listener(&mut self, listener: ListenerConfig) -> Result<u16> {
+        let ListenerConfig {
+            addr,
+            creds,
+            args,
+            handshake_timeout,
+            checkers,
+        } = listener;
+        if self.started {
+            // gRPC C Core rejects ports added after start, and a dedicated core server
+            // created now would never be started.
+            return Err(Error::BindFail(CString::new(addr).unwrap()));
         }
+        let args = with_handshake_timeout(args, handshake_timeout);
+        if args.is_none() && checkers.is_empty() {
+            return self.core.add_listening_port(addr, creds);
+        }
+
+        let merged = match (&self.args, &args) {
+            (Some(server_args), Some(args)) => Some(server_args.merge(args)),
+            _ => None,
+        };
+        let args = merged.as_ref().or(args.as_ref()).or(self.args.as_ref());
+        let core = ServerCore::new(&self.env, args, self.core.slots_per_cq)?;
+        let port = core.add_listening_port(addr, creds)?;
+        self.listeners.push(ListenerCore { core, checkers });
+        Ok(port)
     }
 
     /// Add an rpc channel for an established connection represented as a file
@@ -480,7 +685,7 @@ impl Drop for Server {
     fn drop(&mut self) {
         // if the server is not shutdown completely, destroy a server will core.
         // TODO: don't wait here
-        let f = if !self.core.shutdown.load(Ordering::SeqCst) {
+        let f = if self.cores().any(|c| !c.shutdown.load(Ordering::SeqCst)) {
             Some(self.shutdown())
         } else {
             None
@@ -3,6 +3,7 @@
 mod callback;
 mod executor;
 mod promise;
+mod timer;
 
 use std::fmt::{self, Debug, Formatter};
 use std::future::Future;
@@ -24,6 +25,7 @@ use crate::server::RequestCallContext;
 pub(crate) use self::executor::{Executor, Kicker, UnfinishedWork};
 pub(crate) use self::promise::BatchResult;
 pub use self::promise::BatchType;
+pub(crate) use self::timer::Delay;
 
 /// A handle that is used to notify future that the task finishes.
 pub struct NotifyHandle<T> {
@@ -0,0 +1,225 @@
+// Copyright 2023 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! gRPC C Core doesn't expose its timers through the C API, so a dedicated thread
+//! is used to wake up futures that need to wait for a deadline.
+//!
+//! The timer is only used for client side per message timeout, which is reset
+//! every time a message arrives. To avoid flooding the heap, each `Delay` registers
+//! at most one entry at a time and re-registers itself lazily when the entry expires.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use parking_lot::{const_mutex, Condvar, Mutex};
+
+struct DelayState {
+    // The deadline the delay should fire at, `None` means it's not armed.
+    deadline: Option<Instant>,
+    // The deadline that has been registered to the timer thread.
+    registered: Option<Instant>,
+    waker: Option<Waker>,
+}
+
+struct Entry {
+    deadline: Instant,
+    state: Weak<Mutex<DelayState>>,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Entry) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Entry) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Entry) -> Ordering {
+        self.deadline.cmp(&other.deadline)
+    }
+}
+
+struct TimerInner {
+    entries: Mutex<BinaryHeap<Reverse<Entry>>>,
+    cond: Condvar,
+}
+
+impl TimerInner {
+    fn register(&self, deadline: Instant, state: Weak<Mutex<DelayState>>) {
+        let mut entries = self.entries.lock();
+        let notify = entries.peek().map_or(true, |e| e.0.deadline > deadline);
+        entries.push(Reverse(Entry { deadline, state }));
+        if notify {
+            self.cond.notify_one();
+        }
+    }
+
+    fn run(&self) {
+        let mut entries = self.entries.lock();
+        loop {
+            let now = Instant::now();
+            let next = match entries.peek() {
+                Some(e) if e.0.deadline <= now => entries.pop().unwrap().0,
+                Some(e) => {
+                    let deadline = e.0.deadline;
+                    self.cond.wait_until(&mut entries, deadline);
+                    continue;
+                }
+                None => {
+                    self.cond.wait(&mut entries);
+                    continue;
+                }
+            };
+            let state = match next.state.upgrade() {
+                Some(s) => s,
+                // The delay has been dropped.
+                None => continue,
+            };
+            let mut s = state.lock();
+            if s.registered != Some(next.deadline) {
+                // Stale entry.
+                continue;
+            }
+            s.registered = None;
+            let deadline = s.deadline;
+            match deadline {
+                Some(d) if d <= now => {
+                    let waker = s.waker.take();
+                    drop(s);
+                    if let Some(w) = waker {
+                        w.wake();
+                    }
+                }
+                Some(d) => {
+                    s.registered = Some(d);
+                    entries.push(Reverse(Entry {
+                        deadline: d,
+                        state: Arc::downgrade(&state),
+                    }));
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+static TIMER: Mutex<Option<Arc<TimerInner>>> = const_mutex(None);
+
+fn timer() -> Arc<TimerInner> {
+    let mut timer = TIMER.lock();
+    if let Some(t) = &*timer {
+        return t.clone();
+    }
+    let t = Arc::new(TimerInner {
+        entries: Mutex::new(BinaryHeap::new()),
+        cond: Condvar::new(),
+    });
+    let t1 = t.clone();
+    thread::Builder::new()
+        .name("grpc-timer".to_owned())
+        .spawn(move || t1.run())
+        .expect("failed to spawn grpc timer thread");
+    *timer = Some(t.clone());
+    t
+}
+
+/// A resettable delay that resolves once its deadline is reached.
+pub struct Delay {
+    state: Arc<Mutex<DelayState>>,
+}
+
+impl Delay {
+    /// Create a delay that is not armed.
+    pub fn new() -> Delay {
+        Delay {
+            state: Arc::new(Mutex::new(DelayState {
+                deadline: None,
+                registered: None,
+                waker: None,
+            })),
+        }
+    }
+
+    /// Arm the delay so that it fires after `dur` from now.
+    ///
+    /// Previous deadline is overwritten.
+    pub fn reset(&mut self, dur: Duration) {
+        let deadline = Instant::now() + dur;
+        let mut s = self.state.lock();
+        s.deadline = Some(deadline);
+        if s.registered.map_or(true, |r| r > deadline) {
+            s.registered = Some(deadline);
+            drop(s);
+            timer().register(deadline, Arc::downgrade(&self.state));
+        }
+    }
+
+    /// Disarm the delay.
+    pub fn clear(&mut self) {
+        let mut s = self.state.lock();
+        s.deadline = None;
+        s.waker = None;
+    }
+
+    /// Whether the delay is armed.
+    pub fn is_armed(&self) -> bool {
+        self.state.lock().deadline.is_some()
+    }
+
+    /// Check if the deadline is reached. If not, current task will be notified
+    /// once it's reached.
+    ///
+    /// A delay that is not armed never fires.
+    pub fn poll_elapsed(&mut self, cx: &mut Context) -> Poll<()> {
+        let mut s = self.state.lock();
+        match s.deadline {
+            Some(d) if d <= Instant::now() => Poll::Ready(()),
+            Some(_) => {
+                if s.waker.as_ref().map_or(true, |w| !w.will_wake(cx.waker())) {
+                    s.waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_executor::block_on;
+    use futures_util::future::poll_fn;
+
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let mut delay = Delay::new();
+        assert!(!delay.is_armed());
+
+        let start = Instant::now();
+        delay.reset(Duration::from_millis(100));
+        // Extending the deadline should not fire early.
+        delay.reset(Duration::from_millis(200));
+        block_on(poll_fn(|cx| delay.poll_elapsed(cx)));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        delay.reset(Duration::from_millis(50));
+        delay.clear();
+        assert!(!delay.is_armed());
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(delay.poll_elapsed(&mut cx).is_pending());
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2023 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Generates code for a greeter service with all the options of the compiler
+//! enabled, so that the generated code is at least compiled and tested.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use grpcio_compiler::codegen;
+use grpcio_compiler::options::GenOptions;
+use protobuf::descriptor::*;
+use protobuf::RepeatedField;
+
+fn location(path: Vec<i32>, comments: &str) -> SourceCodeInfo_Location {
+    let mut loc = SourceCodeInfo_Location::new();
+    loc.set_path(path);
+    loc.set_leading_comments(comments.to_owned());
+    loc
+}
+
+fn greeter_file() -> FileDescriptorProto {
+    let mut file = FileDescriptorProto::new();
+    file.set_name("helloworld.proto".to_owned());
+    file.set_package("helloworld".to_owned());
+    for name in &["HelloRequest", "HelloReply"] {
+        let mut msg = DescriptorProto::new();
+        msg.set_name(name.to_string());
+        file.mut_message_type().push(msg);
+    }
+
+    let mut method = MethodDescriptorProto::new();
+    method.set_name("SayHello".to_owned());
+    method.set_input_type(".helloworld.HelloRequest".to_owned());
+    method.set_output_type(".helloworld.HelloReply".to_owned());
+    let mut service = ServiceDescriptorProto::new();
+    service.set_name("Greeter".to_owned());
+    service.mut_method().push(method);
+    file.mut_service().push(service);
+
+    // Indented blocks would fail as doctests if they are not fenced.
+    let mut info = SourceCodeInfo::new();
+    info.set_location(RepeatedField::from_vec(vec![
+        location(
+            vec![6, 0],
+            " The greeting service.\n\n     let client = GreeterClient::new(ch);\n",
+        ),
+        location(vec![6, 0, 2, 0], " Sends a greeting.\n"),
+    ]));
+    file.set_source_code_info(info);
+    file
+}
+
+fn main() {
+    let mut opts = GenOptions::default();
+    opts.visibility = "pub(crate)".to_owned();
+    opts.mock = true;
+    opts.doc_comments = true;
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for res in codegen::gen_with_options(&[greeter_file()], &["helloworld.proto".to_owned()], &opts)
+    {
+        let content = String::from_utf8(res.content).unwrap();
+        // Inner attributes are not allowed in `include!`.
+        let content: String = content
+            .lines()
+            .filter(|l| !l.starts_with("#!["))
+            .map(|l| format!("{}\n", l))
+            .collect();
+        fs::write(Path::new(&out_dir).join(res.name), content).unwrap();
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 TiKV Project Authors. Licensed under Apache-2.0.
+
+//! Code generated by build.rs with all the options of the compiler enabled.
+
+mod helloworld {
+    pub use grpcio_proto::example::helloworld::{HelloReply, HelloRequest};
+}
+
+#[allow(clippy::all, unused_imports)]
+mod helloworld_grpc {
+    include!(concat!(env!("OUT_DIR"), "/helloworld_grpc.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use grpcio::*;
+
+    use super::helloworld::*;
+    use super::helloworld_grpc::*;
+
+    #[test]
+    fn test_generated_mock() {
+        let env = Arc::new(Environment::new(1));
+        let mock = GreeterMock {
+            say_hello: Some(Arc::new(
+                |ctx: RpcContext<'_>, req: HelloRequest, sink: UnarySink<HelloReply>| {
+                    let mut resp = HelloReply::default();
+                    resp.set_message(format!("hello {}", req.get_name()));
+                    ctx.spawn(async move {
+                        sink.success(resp).await.unwrap();
+                    });
+                },
+            )),
+        };
+        let mut server = ServerBuilder::new(env.clone())
+            .register_service(create_greeter(mock))
+            .build()
+            .unwrap();
+        let port = server
+            .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+            .unwrap();
+        server.start();
+        let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{}", port));
+        let client = GreeterClient::new(ch);
+        let mut req = HelloRequest::default();
+        req.set_name("mock".to_owned());
+        let resp = client.say_hello(&req).unwrap();
+        assert_eq!(resp.get_message(), "hello mock");
+
+        // Handlers that are not set should return UNIMPLEMENTED.
+        let mut server = ServerBuilder::new(env.clone())
+            .register_service(create_greeter(GreeterMock::default()))
+            .build()
+            .unwrap();
+        let port = server
+            .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+            .unwrap();
+        server.start();
+        let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{}", port));
+        let client = GreeterClient::new(ch);
+        match client.say_hello(&req) {
+            Err(Error::RpcFailure(s)) => assert_eq!(s.code(), RpcStatusCode::UNIMPLEMENTED),
+            res => panic!("expect unimplemented, got {:?}", res),
+        }
+    }
+}
@@ -1,3 +1,8 @@
 // Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
 
 pub mod util;
+
+// Doc comments of the generated code are also checked by doctests.
+#[cfg(all(feature = "protobuf-codec", any(test, doctest)))]
+#[allow(dead_code)]
+mod codegen;
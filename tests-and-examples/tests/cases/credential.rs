@@ -3,13 +3,14 @@
 use futures_util::future::{FutureExt as _, TryFutureExt as _};
 
 use grpcio::{
-    CertificateRequestType, ChannelBuilder, ChannelCredentialsBuilder, EnvBuilder, RpcContext,
-    ServerBuilder, ServerCredentials, ServerCredentialsBuilder, ServerCredentialsFetcher,
-    UnarySink,
+    CallOption, CertificateRequestType, ChannelBuilder, ChannelCredentialsBuilder, EnvBuilder,
+    ListenerConfig, RpcContext, RpcStatusCode, ServerBuilder, ServerCredentials,
+    ServerCredentialsBuilder, ServerCredentialsFetcher, UnarySink,
 };
 use grpcio_proto::example::helloworld::*;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tests_and_examples::util::{read_cert_pair, read_single_crt};
 
 #[derive(Clone)]
@@ -166,3 +167,113 @@ fn test_reload_fail() {
         assert_eq!(reply.get_message(), "Hello world");
     }
 }
+
+#[test]
+fn test_listener_credentials() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(GreeterService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let insecure_port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    let (server1_crt, server1_key) = read_cert_pair("server1").unwrap();
+    let server_creds = || {
+        ServerCredentialsBuilder::new()
+            .add_cert(server1_crt.clone().into(), server1_key.clone().into())
+            .build()
+    };
+    let secure_port = server
+        .add_listener(ListenerConfig::new("127.0.0.1:0").credentials(server_creds()))
+        .unwrap();
+    // Listeners with their own arguments are served by a dedicated core server.
+    let args = ChannelBuilder::new(env.clone())
+        .max_receive_message_len(1024)
+        .build_args();
+    let dedicated_port = server
+        .add_listener(
+            ListenerConfig::new("127.0.0.1:0")
+                .credentials(server_creds())
+                .channel_args(args),
+        )
+        .unwrap();
+    server.start();
+
+    let mut req = HelloRequest::default();
+    req.set_name("world".to_owned());
+    let opt = CallOption::default().timeout(Duration::from_secs(5));
+    let connect = |port: u16, secure: bool| {
+        let mut builder = ChannelBuilder::new(env.clone());
+        if secure {
+            // The CN field in the certificate of server1 is "*.test.google.fr".
+            let creds = ChannelCredentialsBuilder::new()
+                .root_cert(read_single_crt("ca").unwrap().into())
+                .build();
+            builder = builder
+                .override_ssl_target("rust.test.google.fr")
+                .set_credentials(creds);
+        }
+        GreeterClient::new(builder.connect(&format!("127.0.0.1:{port}")))
+    };
+
+    for port in [secure_port, dedicated_port] {
+        let reply = connect(port, true)
+            .say_hello_opt(&req, opt.clone())
+            .expect("rpc");
+        assert_eq!(reply.get_message(), "Hello world");
+        connect(port, false)
+            .say_hello_opt(&req, opt.clone())
+            .unwrap_err();
+    }
+    // Credentials of a listener should not affect the others.
+    let reply = connect(insecure_port, false)
+        .say_hello_opt(&req, opt.clone())
+        .expect("rpc");
+    assert_eq!(reply.get_message(), "Hello world");
+    connect(insecure_port, true)
+        .say_hello_opt(&req, opt)
+        .unwrap_err();
+}
+
+#[test]
+fn test_connect_failures() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_greeter(GreeterService);
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+
+    let req = HelloRequest::default();
+    let opt = CallOption::default().timeout(Duration::from_secs(5));
+    // TLS handshake fails as the listener is plaintext.
+    let creds = ChannelCredentialsBuilder::new()
+        .root_cert(read_single_crt("ca").unwrap().into())
+        .build();
+    let ch = ChannelBuilder::new(env.clone())
+        .set_credentials(creds)
+        .connect(&format!("127.0.0.1:{port}"));
+    let handshake_err = GreeterClient::new(ch)
+        .say_hello_opt(&req, opt.clone())
+        .unwrap_err();
+    // TCP connect fails as nothing listens on the port.
+    let ch = ChannelBuilder::new(env).connect("127.0.0.1:1");
+    let connect_err = GreeterClient::new(ch).say_hello_opt(&req, opt).unwrap_err();
+
+    // gRPC C Core 1.44 reports both phases in the same way.
+    for e in [handshake_err, connect_err] {
+        match e {
+            grpcio::Error::RpcFailure(s) => {
+                assert_eq!(s.code(), RpcStatusCode::UNAVAILABLE);
+                assert_eq!(s.message(), "failed to connect to all addresses");
+            }
+            e => panic!("expected rpc failure, but got: {:?}", e),
+        }
+    }
+}
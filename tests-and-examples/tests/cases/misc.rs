@@ -235,6 +235,162 @@ fn test_shutdown_when_exists_grpc_call() {
     );
 }
 
+#[test]
+fn test_set_channel() {
+    let env = Arc::new(Environment::new(2));
+    let mut slow_server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(SleepService(true)))
+        .build()
+        .unwrap();
+    let slow_port = slow_server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    slow_server.start();
+    let mut peer_server = ServerBuilder::new(env.clone())
+        .register_service(create_greeter(PeerService))
+        .build()
+        .unwrap();
+    let peer_port = peer_server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    peer_server.start();
+
+    let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{slow_port}"));
+    let client = GreeterClient::new(ch);
+    let cloned = client.clone();
+    let req = HelloRequest::default();
+    let in_flight = client.say_hello_async(&req).unwrap();
+
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{peer_port}"));
+    client.set_channel(ch);
+    // New calls should be sent to the new channel, including the ones of clones.
+    let resp = client.say_hello(&req).unwrap();
+    assert!(resp.get_message().contains("127.0.0.1"), "{:?}", resp);
+    let resp = cloned.say_hello(&req).unwrap();
+    assert!(resp.get_message().contains("127.0.0.1"), "{:?}", resp);
+    // In-flight calls should continue on the old channel even it's dropped.
+    let resp = block_on(in_flight).unwrap();
+    assert!(resp.get_message().is_empty(), "{:?}", resp);
+}
+
+#[derive(Clone)]
+struct ReplicaService {
+    name: &'static str,
+    delay: Duration,
+    // Set when the reply fails, which means the call is canceled by client.
+    canceled: Arc<AtomicBool>,
+}
+
+impl Greeter for ReplicaService {
+    fn say_hello(&mut self, ctx: RpcContext<'_>, _: HelloRequest, sink: UnarySink<HelloReply>) {
+        let (name, delay, canceled) = (self.name, self.delay, self.canceled.clone());
+        ctx.spawn(async move {
+            Delay::new(delay).await;
+            let mut resp = HelloReply::default();
+            resp.set_message(name.to_owned());
+            if sink.success(resp).await.is_err() {
+                canceled.store(true, Ordering::SeqCst);
+            }
+        });
+    }
+}
+
+/// Wait until the slow replica finds its call canceled.
+fn wait_canceled(canceled: &AtomicBool) {
+    let start = Instant::now();
+    while !canceled.swap(false, Ordering::SeqCst) {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "call to slow replica is not canceled"
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_hedged_unary_call() {
+    let env = Arc::new(Environment::new(2));
+    let mut clients = vec![];
+    let mut servers = vec![];
+    let slow_canceled = Arc::new(AtomicBool::new(false));
+    let fast_canceled = Arc::new(AtomicBool::new(false));
+    for (name, delay, canceled) in [
+        ("slow", 1500, slow_canceled.clone()),
+        ("fast", 0, fast_canceled.clone()),
+    ] {
+        let service = ReplicaService {
+            name,
+            delay: Duration::from_millis(delay),
+            canceled,
+        };
+        let mut server = ServerBuilder::new(env.clone())
+            .register_service(create_greeter(service))
+            .build()
+            .unwrap();
+        let port = server
+            .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+            .unwrap();
+        server.start();
+        servers.push(server);
+        let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{port}"));
+        clients.push(GreeterClient::new(ch));
+    }
+
+    let req = HelloRequest::default();
+    let say_hello = |c: &GreeterClient| c.say_hello_async_opt(&req, CallOption::default());
+    let start = Instant::now();
+    let resp = block_on(hedged_unary_call(
+        &clients,
+        say_hello,
+        Duration::from_millis(100),
+    ))
+    .unwrap();
+    assert_eq!(resp.get_message(), "fast");
+    assert!(
+        start.elapsed() < Duration::from_secs(1),
+        "{:?}",
+        start.elapsed()
+    );
+    // The slow call should be canceled once the fast one wins.
+    wait_canceled(&slow_canceled);
+
+    // A zero stagger sends to all replicas at once.
+    let resp = block_on(hedged_unary_call(&clients, say_hello, Duration::ZERO)).unwrap();
+    assert_eq!(resp.get_message(), "fast");
+    wait_canceled(&slow_canceled);
+
+    // Dropping the hedged call should cancel the calls in flight.
+    let hedged = Box::pin(hedged_unary_call(&clients[..1], say_hello, Duration::ZERO));
+    let timeout = Delay::new(Duration::from_millis(500));
+    match block_on(future::select(hedged, timeout)) {
+        future::Either::Right((_, hedged)) => drop(hedged),
+        future::Either::Left((res, _)) => panic!("unexpected result {:?}", res),
+    }
+    wait_canceled(&slow_canceled);
+    assert!(!fast_canceled.load(Ordering::SeqCst));
+
+    // All replicas fail, channels can be used as replicas directly.
+    let channels: Vec<_> = (0..2)
+        .map(|_| ChannelBuilder::new(env.clone()).connect("127.0.0.1:1"))
+        .collect();
+    let opt = CallOption::default().timeout(Duration::from_millis(500));
+    let say_hello =
+        |ch: &Channel| GreeterClient::new(ch.clone()).say_hello_async_opt(&req, opt.clone());
+    match block_on(hedged_unary_call(
+        &channels,
+        say_hello,
+        Duration::from_millis(100),
+    )) {
+        Err(Error::HedgeFailure(errors)) => {
+            assert_eq!(errors.len(), 2, "{:?}", errors);
+            for e in errors {
+                assert!(matches!(e, Error::RpcFailure(_)), "{:?}", e);
+            }
+        }
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
 #[test]
 fn test_custom_checker_server_side() {
     let flag = Arc::new(atomic::AtomicBool::new(false));
@@ -266,6 +422,73 @@ fn test_custom_checker_server_side() {
     );
 }
 
+#[test]
+fn test_listener_config() {
+    let env = Arc::new(Environment::new(2));
+    let service = create_greeter(PeerService);
+    let args = ChannelBuilder::new(env.clone())
+        .max_receive_message_len(16)
+        .build_args();
+    let mut server = ServerBuilder::new(env.clone())
+        .channel_args(args)
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    let checker = FlagChecker {
+        flag: Arc::new(atomic::AtomicBool::new(true)),
+    };
+    let checked_port = server
+        .add_listener(ListenerConfig::new("127.0.0.1:0").add_checker(checker.clone()))
+        .unwrap();
+    let args = ChannelBuilder::new(env.clone())
+        .tcp_read_chunk_size(4096)
+        .build_args();
+    let merged_port = server
+        .add_listener(ListenerConfig::new("127.0.0.1:0").channel_args(args))
+        .unwrap();
+    let args = ChannelBuilder::new(env.clone())
+        .max_receive_message_len(1024)
+        .build_args();
+    let overridden_port = server
+        .add_listener(ListenerConfig::new("127.0.0.1:0").channel_args(args))
+        .unwrap();
+    server.start();
+    // A dedicated listener added now would never be served.
+    match server.add_listener(ListenerConfig::new("127.0.0.1:0").add_checker(checker)) {
+        Err(grpcio::Error::BindFail(_)) => (),
+        res => panic!("expected bind failure, but got: {:?}", res),
+    }
+
+    let connect = |port: u16| {
+        let ch = ChannelBuilder::new(env.clone()).connect(&format!("127.0.0.1:{port}"));
+        GreeterClient::new(ch)
+    };
+    let assert_exhausted = |port: u16, req: &HelloRequest| match connect(port).say_hello(req) {
+        Err(grpcio::Error::RpcFailure(s)) => {
+            assert_eq!(s.code(), RpcStatusCode::RESOURCE_EXHAUSTED)
+        }
+        res => panic!("expected resource exhausted, but got: {:?}", res),
+    };
+
+    // Options of a listener should not affect the others.
+    assert_eq!(
+        connect(checked_port)
+            .say_hello(&HelloRequest::default())
+            .unwrap_err()
+            .to_string(),
+        "RpcFailure: 15-DATA_LOSS".to_owned()
+    );
+    let mut req = HelloRequest::default();
+    req.set_name("a name longer than 16 bytes".to_owned());
+    assert_exhausted(port, &req);
+    // Server wide arguments are merged into the ones of a listener.
+    assert_exhausted(merged_port, &req);
+    connect(overridden_port).say_hello(&req).unwrap();
+}
+
 #[derive(Clone)]
 struct FlagChecker {
     flag: Arc<atomic::AtomicBool>,
@@ -1,6 +1,7 @@
 // Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures_channel::mpsc;
 use futures_executor::block_on;
@@ -10,8 +11,9 @@ use futures_util::{
     FutureExt as _, SinkExt as _, StreamExt as _, TryFutureExt as _, TryStreamExt as _,
 };
 use grpcio::{
-    ChannelBuilder, ClientStreamingSink, DuplexSink, EnvBuilder, RequestStream, RpcContext,
-    ServerBuilder, ServerCredentials, ServerStreamingSink, UnarySink, WriteFlags,
+    CallOption, ChannelBuilder, ClientStreamingSink, DuplexSink, EnvBuilder, RequestStream,
+    RpcContext, RpcStatus, RpcStatusCode, ServerBuilder, ServerCredentials, ServerStreamingSink,
+    UnarySink, WriteFlags,
 };
 use grpcio_proto::example::route_guide::*;
 
@@ -24,8 +26,24 @@ impl RouteGuide for RouteGuideService {
     fn get_feature(&mut self, _: RpcContext<'_>, _: Point, _: UnarySink<Feature>) {
         unimplemented!()
     }
-    fn list_features(&mut self, _: RpcContext<'_>, _: Rectangle, _: ServerStreamingSink<Feature>) {
-        unimplemented!()
+    fn list_features(
+        &mut self,
+        ctx: RpcContext<'_>,
+        _: Rectangle,
+        mut sink: ServerStreamingSink<Feature>,
+    ) {
+        let f = async move {
+            sink.send((Feature::default(), WriteFlags::default()))
+                .await?;
+            // Keep silent for a while so client can observe the message timeout.
+            Delay::new(Duration::from_secs(3)).await;
+            sink.send((Feature::default(), WriteFlags::default()))
+                .await?;
+            sink.close().await?;
+            Ok(())
+        }
+        .map(|_: grpcio::Result<()>| ());
+        ctx.spawn(f)
     }
     fn record_route(
         &mut self,
@@ -68,6 +86,88 @@ impl RouteGuide for RouteGuideService {
     }
 }
 
+#[derive(Clone)]
+struct AdapterService {}
+
+impl RouteGuide for AdapterService {
+    fn get_feature(&mut self, _: RpcContext<'_>, _: Point, _: UnarySink<Feature>) {
+        unimplemented!()
+    }
+    fn list_features(
+        &mut self,
+        ctx: RpcContext<'_>,
+        _: Rectangle,
+        sink: ServerStreamingSink<Feature>,
+    ) {
+        let f = async move {
+            // Features without name should not be sent.
+            let mut sink = sink.filter_msg(|f: &Feature| !f.get_name().is_empty());
+            for name in ["a", "", "b"] {
+                let mut f = Feature::default();
+                f.set_name(name.to_owned());
+                sink.send((f, WriteFlags::default())).await?;
+            }
+            sink.close().await?;
+            Ok(())
+        }
+        .map(|_: grpcio::Result<()>| ());
+        ctx.spawn(f)
+    }
+    fn record_route(
+        &mut self,
+        ctx: RpcContext<'_>,
+        points: RequestStream<Point>,
+        resp: ClientStreamingSink<RouteSummary>,
+    ) {
+        let f = async move {
+            let mut latitudes = points
+                .filter_msg(|p| p.get_latitude() % 2 == 0)
+                .map_msg(|p| p.get_latitude());
+            let mut summary = RouteSummary::default();
+            while let Some(latitude) = latitudes.try_next().await? {
+                summary.point_count += 1;
+                summary.distance += latitude;
+            }
+            resp.success(summary).await?;
+            Ok(())
+        }
+        .map(|_: grpcio::Result<()>| ());
+        ctx.spawn(f)
+    }
+    fn route_chat(
+        &mut self,
+        ctx: RpcContext<'_>,
+        notes: RequestStream<RouteNote>,
+        sink: DuplexSink<RouteNote>,
+    ) {
+        let f = async move {
+            let mut messages = notes.try_map_msg(|mut n| {
+                if n.get_message().is_empty() {
+                    let status =
+                        RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, "empty".into());
+                    return Err(grpcio::Error::RpcFailure(status));
+                }
+                Ok(n.take_message())
+            });
+            let mut sink = sink.map_msg(|msg: String| {
+                let mut n = RouteNote::default();
+                n.set_message(msg.to_uppercase());
+                n
+            });
+            loop {
+                match messages.try_next().await {
+                    Ok(Some(msg)) => sink.send((msg, WriteFlags::default())).await?,
+                    Ok(None) => return sink.close().await,
+                    Err(grpcio::Error::RpcFailure(s)) => return sink.into_inner().fail(s).await,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        .map(|_: grpcio::Result<()>| ());
+        ctx.spawn(f)
+    }
+}
+
 macro_rules! assert_finish {
     ($res:expr) => {
         match $res {
@@ -161,3 +261,85 @@ fn test_client_send_all() {
     };
     block_on(exec_test_f);
 }
+
+#[test]
+fn test_message_timeout() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_route_guide(RouteGuideService {});
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = RouteGuideClient::new(ch);
+
+    let opt = CallOption::default().message_timeout(Duration::from_millis(500));
+    let mut receiver = client
+        .list_features_opt(&Rectangle::default(), opt)
+        .unwrap();
+    block_on(async move {
+        receiver.try_next().await.unwrap().unwrap();
+        match receiver.try_next().await {
+            Err(grpcio::Error::RpcFailure(s)) => {
+                assert_eq!(s.code(), RpcStatusCode::DEADLINE_EXCEEDED)
+            }
+            res => panic!("expected message timeout, but got: {:?}", res),
+        }
+    });
+}
+
+#[test]
+fn test_stream_adapters() {
+    let env = Arc::new(EnvBuilder::new().build());
+    let service = create_route_guide(AdapterService {});
+    let mut server = ServerBuilder::new(env.clone())
+        .register_service(service)
+        .build()
+        .unwrap();
+    let port = server
+        .add_listening_port("127.0.0.1:0", ServerCredentials::insecure())
+        .unwrap();
+    server.start();
+    let ch = ChannelBuilder::new(env).connect(&format!("127.0.0.1:{port}"));
+    let client = RouteGuideClient::new(ch);
+
+    block_on(async move {
+        let receiver = client.list_features(&Rectangle::default()).unwrap();
+        let features: Vec<_> = receiver.try_collect().await.unwrap();
+        let names: Vec<_> = features.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        let (mut sink, receiver) = client.record_route().unwrap();
+        for i in 0..10 {
+            let mut p = Point::default();
+            p.set_latitude(i);
+            sink.send((p, WriteFlags::default())).await.unwrap();
+        }
+        sink.close().await.unwrap();
+        let summary = receiver.await.unwrap();
+        assert_eq!(summary.get_point_count(), 5);
+        assert_eq!(summary.get_distance(), 20);
+
+        let (mut sink, mut receiver) = client.route_chat().unwrap();
+        for msg in ["a", "b"] {
+            let mut n = RouteNote::default();
+            n.set_message(msg.to_owned());
+            sink.send((n, WriteFlags::default())).await.unwrap();
+            let n = receiver.try_next().await.unwrap().unwrap();
+            assert_eq!(n.get_message(), msg.to_uppercase());
+        }
+        sink.send((RouteNote::default(), WriteFlags::default()))
+            .await
+            .unwrap();
+        match receiver.try_next().await {
+            Err(grpcio::Error::RpcFailure(s)) => {
+                assert_eq!(s.code(), RpcStatusCode::INVALID_ARGUMENT)
+            }
+            res => panic!("expected invalid argument, but got: {:?}", res),
+        }
+    });
+}
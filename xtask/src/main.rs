@@ -15,10 +15,12 @@ fn print_help() {
     eprintln!();
     eprintln!("Supported subcommands are:");
     eprintln!("\tbindgen\tGenerate rust-bindgen for grpcio-sys package");
+    eprintln!("\tchannel-args\tGenerate the list of known channel arguments from bindings");
     eprintln!("\tsubmodule\tInit necessary submodules for compilation");
     eprintln!("\tclang-lint\tLint cpp code in grpcio-sys package");
     eprintln!("\tcodegen\tGenerate rust code for all protocols");
     eprintln!("\trefresh-package\tRegenerate grpc-sys/link-deps.rs to show the latest linking dependencies.");
+    eprintln!("\tpreflight\tBuild all feature combinations, check public API changes and generated code before a release");
 }
 
 fn cargo() -> Command {
@@ -65,6 +67,56 @@ fn bindgen() {
     );
 }
 
+/// Collects all channel argument keys defined in gRPC C Core headers.
+///
+/// Bindings are generated from the headers directly, so scanning the byte string
+/// constants with a value prefixed by `grpc.` is enough.
+fn channel_args() {
+    let bindings = fs::read_to_string("grpc-sys/bindings/bindings.rs").unwrap();
+    let mut names = vec![];
+    let mut decl = String::new();
+    for l in bindings.lines() {
+        if decl.is_empty() && !l.starts_with("pub const ") {
+            continue;
+        }
+        if !decl.is_empty() {
+            decl.push(' ');
+        }
+        decl.push_str(l.trim());
+        if !decl.ends_with(';') {
+            continue;
+        }
+        let d = std::mem::take(&mut decl);
+        let (name, value) = match d["pub const ".len()..].split_once(':') {
+            Some((n, v)) => (n.to_owned(), v.to_owned()),
+            None => continue,
+        };
+        if value.trim_start().starts_with("&[u8;") && value.contains("= b\"grpc.") {
+            names.push(name);
+        }
+    }
+    names.sort_unstable();
+    names.dedup();
+
+    let mut f = File::create("grpc-sys/src/channel_args.rs").unwrap();
+    f.write_all(
+        b"// Generated by `cargo xtask channel-args`, don't edit it manually.
+
+use crate::bindings::*;
+
+/// All channel argument keys defined in gRPC C Core headers.
+pub const KNOWN_CHANNEL_ARGS: &[&[u8]] = &[
+",
+    )
+    .unwrap();
+    for name in names {
+        writeln!(f, "{},", name).unwrap();
+    }
+    writeln!(f, "];").unwrap();
+    drop(f);
+    exec(Command::new("rustfmt").args(&["grpc-sys/src/channel_args.rs"]));
+}
+
 fn cmd(c: impl AsRef<OsStr>) -> Command {
     Command::new(c)
 }
@@ -256,6 +308,111 @@ fn refresh_link_package() {
     exec(Command::new("rustfmt").args(&["grpc-sys/link-deps.rs"]));
 }
 
+/// Feature combinations that are supposed to build. Secure features are
+/// mutually exclusive, so they are checked with each codec separately.
+const FEATURE_SETS: &[&[&str]] = &[
+    &["--no-default-features"],
+    &["--no-default-features", "--features", "protobuf-codec"],
+    &["--no-default-features", "--features", "prost-codec"],
+    &[
+        "--no-default-features",
+        "--features",
+        "protobuf-codec,boringssl",
+    ],
+    &[
+        "--no-default-features",
+        "--features",
+        "prost-codec,boringssl",
+    ],
+    &[
+        "--no-default-features",
+        "--features",
+        "protobuf-codec,openssl",
+    ],
+    &["--no-default-features", "--features", "prost-codec,openssl"],
+    &[],
+];
+
+/// Packages that are built with each codec separately, from their own directories
+/// so that the features apply to them instead of the root package.
+const CODEC_PACKAGES: &[&str] = &["proto", "health", "compiler"];
+
+/// Public API checks, the package and the features of its API to compare.
+const API_CHECKS: &[(&str, &[&str])] = &[
+    ("grpcio", &[]),
+    (
+        "grpcio",
+        &["--only-explicit-features", "--features", "prost-codec"],
+    ),
+    ("grpcio-sys", &[]),
+];
+
+/// Files that are generated and should be committed.
+const GENERATED: &[&str] = &[
+    "health/src/proto",
+    "proto/src/proto",
+    "grpc-sys/src/channel_args.rs",
+];
+
+/// Runs the checks that keep slipping through CI before cutting a release.
+///
+/// Public API is compared with the latest published version by cargo-semver-checks,
+/// which needs to be installed by `cargo install cargo-semver-checks`. Generated code
+/// is regenerated and must leave no changes or untracked files in the generated paths.
+fn preflight() {
+    for features in FEATURE_SETS {
+        exec(cargo().arg("build").args(*features));
+    }
+    // Generated code and codegen are only checked by the codec they are for.
+    for dir in CODEC_PACKAGES {
+        for codec in &["protobuf-codec", "prost-codec"] {
+            exec(
+                cargo()
+                    .args(&["build", "--no-default-features", "--features", codec])
+                    .current_dir(dir),
+            );
+        }
+    }
+
+    match cargo().args(&["semver-checks", "--version"]).output() {
+        Ok(o) if o.status.success() => {}
+        _ => {
+            eprintln!("cargo-semver-checks is not found, please install it by `cargo install cargo-semver-checks`");
+            process::exit(1);
+        }
+    }
+    for (package, features) in API_CHECKS {
+        exec(
+            cargo()
+                .args(&["semver-checks", "check-release", "-p", package])
+                .args(*features),
+        );
+    }
+
+    codegen();
+    channel_args();
+    // Unlike `git diff`, untracked files are also reported.
+    let mut c = cmd("git");
+    c.args(&["status", "--porcelain", "--untracked-files=all", "--"])
+        .args(GENERATED);
+    let output = match c.output() {
+        Ok(o) if o.status.success() => o,
+        Ok(o) => {
+            eprintln!("failed to execute {:?}: {}", c, o.status);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("failed to execute {:?}: {}", c, e);
+            process::exit(1);
+        }
+    };
+    if !output.stdout.is_empty() {
+        eprintln!("generated code is not committed:");
+        eprint!("{}", String::from_utf8_lossy(&output.stdout));
+        process::exit(1);
+    }
+}
+
 fn main() {
     let mut args = env::args();
     if args.len() != 2 {
@@ -265,11 +422,16 @@ fn main() {
     args.next();
     let subcommand = args.next().unwrap();
     match &*subcommand {
-        "bindgen" => bindgen(),
+        "bindgen" => {
+            bindgen();
+            channel_args();
+        }
+        "channel-args" => channel_args(),
         "submodule" => submodule(),
         "clang-lint" => clang_lint(),
         "codegen" => codegen(),
         "refresh-package" => refresh_link_package(),
+        "preflight" => preflight(),
         _ => print_help(),
     }
 }